@@ -84,6 +84,22 @@ where
         TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data)
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
     fn event(
         &mut self,
         origin: Vector2,
@@ -115,7 +131,7 @@ where
             );
             self.lens
                 .with_mut(widget, |widget_data| *widget_data = value);
-            return Some(CommonReaction::ReLayout.into());
+            return Some(CommonReaction::ReLayoutSelf.into());
         }
         child_reaction
     }
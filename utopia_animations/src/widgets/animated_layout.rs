@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use keyframe::{functions::Linear, CanTween, EasingFunction};
+use utopia_core::{
+    controllers::TransformEvent,
+    math::{Rectangle, Size, Vector2},
+    widgets::{pod, TypedWidget, Widget},
+    Backend, BoxConstraints,
+};
+
+use crate::event::AnimateEvent;
+
+/// Animates the changes of position and size of a widget
+///
+/// The child is laid out at its new geometry right away, but drawn at a geometry moving
+/// towards it over `AnimateEvent` ticks. Only moves made by layouts are animated,
+/// so the child still follows e.g. a scrolled viewport instantly, see `pod::layout_origin`.
+///
+/// * TW is the wrapped Widget
+/// * EF is an EasingFunction implementor
+pub struct AnimatedLayout<T, TW, EF, B> {
+    widget: TW,
+    easing_func: EF,
+    duration: Duration,
+    /// Geometry given to the widget as of the last tick
+    target: Option<Rectangle>,
+    /// Geometry given to the widget by layouts as of the last tick, ignoring scrolling
+    placement: Option<Rectangle>,
+    transition: Option<LayoutTransition>,
+    _t: std::marker::PhantomData<T>,
+    _b: std::marker::PhantomData<B>,
+}
+
+/// A move in progress
+struct LayoutTransition {
+    from: Rectangle,
+    elapsed: Duration,
+}
+
+impl<T, TW: TypedWidget<T, B>, B: Backend> AnimatedLayout<T, TW, Linear, B> {
+    pub fn new(widget: TW) -> Self {
+        AnimatedLayout {
+            widget,
+            easing_func: Linear,
+            duration: Duration::from_millis(250),
+            target: None,
+            placement: None,
+            transition: None,
+            _t: std::marker::PhantomData,
+            _b: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, TW, EF, B> AnimatedLayout<T, TW, EF, B> {
+    pub fn easing<NEF: EasingFunction>(
+        self,
+        easing_function: NEF,
+    ) -> AnimatedLayout<T, TW, NEF, B> {
+        let AnimatedLayout {
+            widget,
+            duration,
+            target,
+            placement,
+            transition,
+            ..
+        } = self;
+
+        AnimatedLayout {
+            widget,
+            easing_func: easing_function,
+            duration,
+            target,
+            placement,
+            transition,
+            _t: std::marker::PhantomData,
+            _b: std::marker::PhantomData,
+        }
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+impl<T, TW, EF: EasingFunction, B> AnimatedLayout<T, TW, EF, B> {
+    /// Geometry the widget is drawn at, given its actual geometry
+    fn displayed(&self, origin: Vector2, size: Size) -> (Vector2, Size) {
+        let (transition, target) = match (&self.transition, &self.target) {
+            (Some(transition), Some(target)) => (transition, target),
+            _ => return (origin, size),
+        };
+        let time_factor = match self.duration.as_secs_f64() > 0. {
+            true => transition.elapsed.as_secs_f64() / self.duration.as_secs_f64(),
+            false => 1.,
+        };
+        let progress = self.easing_func.y(time_factor);
+
+        // The target may have moved without a layout since the last tick
+        let from = transition.from.origin + (origin - target.origin);
+        let origin = Vector2::new(
+            f32::ease(from.x, origin.x, progress),
+            f32::ease(from.y, origin.y, progress),
+        );
+        let size = Size::new(
+            f32::ease(transition.from.size.width, size.width, progress),
+            f32::ease(transition.from.size.height, size.height, progress),
+        );
+        (origin, size)
+    }
+
+    fn tick(&mut self, origin: Vector2, size: Size, elapsed: Duration) {
+        let placement = Rectangle {
+            origin: pod::layout_origin(),
+            size,
+        };
+
+        // Step 1 : Start a transition from the displayed geometry when a layout moved the widget
+        if let (Some(previous), Some(previous_placement)) = (self.target, self.placement) {
+            let moved = previous_placement.origin != placement.origin
+                || previous_placement.size != placement.size;
+            if moved {
+                let (from_origin, from_size) = self.displayed(previous.origin, previous.size);
+                self.transition = Some(LayoutTransition {
+                    from: Rectangle {
+                        origin: from_origin,
+                        size: from_size,
+                    },
+                    elapsed: Duration::default(),
+                });
+            } else if let Some(transition) = self.transition.as_mut() {
+                // Other moves are followed instantly
+                transition.from.origin = transition.from.origin + (origin - previous.origin);
+            }
+        }
+        self.target = Some(Rectangle { origin, size });
+        self.placement = Some(placement);
+
+        // Step 2 : Advance the transition in progress
+        if let Some(transition) = self.transition.as_mut() {
+            transition.elapsed = (transition.elapsed + elapsed).min(self.duration);
+            if transition.elapsed >= self.duration {
+                self.transition = None;
+            }
+        }
+    }
+}
+
+impl<T, TW: TypedWidget<T, B>, EF: EasingFunction, B: Backend> Widget<T>
+    for AnimatedLayout<T, TW, EF, B>
+where
+    B::Event: TransformEvent<AnimateEvent> + Clone,
+{
+    type Primitive = B::Primitive;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data)
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        TypedWidget::<T, B>::baseline(&self.widget, context, data)
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        let (origin, size) = self.displayed(origin, size);
+        TypedWidget::<T, B>::draw(&self.widget, origin, size, data)
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        if let Some(AnimateEvent { elapsed }) = event.clone().transform_event() {
+            self.tick(origin, size, elapsed);
+        }
+        // Events reach the widget where it is laid out
+        TypedWidget::<T, B>::event(&mut self.widget, origin, size, data, event)
+    }
+}
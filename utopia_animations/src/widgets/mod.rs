@@ -6,6 +6,7 @@ use utopia_core::{lens::Lens, widgets::TypedWidget, Backend};
 use self::animated::Animated;
 
 pub mod animated;
+pub mod animated_layout;
 
 pub trait AnimationExt<T, B: Backend>: Sized + TypedWidget<T, B> + Deref + DerefMut {
     fn animate<L: Lens<<Self as Deref>::Target, U>, U: Clone + CanTween>(
@@ -1,5 +1,6 @@
 use crate::{
     math::{Size, Vector2},
+    reactions::CommonReaction,
     widgets::Widget,
 };
 
@@ -33,7 +34,7 @@ pub enum MouseButton {
 
 impl<T, W: Widget<T>> Controller<T, W> for Click<T> {
     type Event = MouseClickEvent;
-    type Reaction = ();
+    type Reaction = CommonReaction;
 
     fn event(
         &mut self,
@@ -49,10 +50,11 @@ impl<T, W: Widget<T>> Controller<T, W> for Click<T> {
         }
 
         match event.mouse_button {
-            MouseButton::Left => (self.callback)(data),
-            _ => (),
+            MouseButton::Left => {
+                (self.callback)(data);
+                Some(CommonReaction::ReLayout)
+            }
+            _ => None,
         }
-
-        None
     }
 }
@@ -0,0 +1,20 @@
+use crate::math::Vector2;
+
+/// Pointer events describing a drag gesture of the primary mouse button
+#[derive(Debug, Clone)]
+pub enum DragEvent {
+    /// The button was pressed at the given position
+    Pressed(Vector2),
+    /// The pointer moved to the given position
+    Moved(Vector2),
+    /// The button was released at the given position
+    Released(Vector2),
+}
+
+impl DragEvent {
+    pub fn pos(&self) -> Vector2 {
+        match self {
+            DragEvent::Pressed(pos) | DragEvent::Moved(pos) | DragEvent::Released(pos) => *pos,
+        }
+    }
+}
@@ -5,6 +5,8 @@ use crate::{
 };
 
 pub mod click;
+pub mod drag;
+pub mod wheel;
 
 /// A Controller is a bit of logic that reacts to a specific Event type.
 pub trait Controller<T, W: Widget<T>> {
@@ -0,0 +1,8 @@
+use crate::math::Vector2;
+
+#[derive(Debug, Clone)]
+pub struct MouseWheelEvent {
+    pub pos: Vector2,
+    /// Distance the content should scroll by, positive values going right and down
+    pub delta: Vector2,
+}
@@ -1,6 +1,11 @@
 use crate::{
     math::Size,
-    steps::{event::EventStep, layout::LayoutStep, paint::PaintStep},
+    reactions::LayoutReaction,
+    steps::{
+        event::EventStep,
+        layout::{LayoutError, LayoutStep},
+        paint::PaintStep,
+    },
     widgets::{pod::WidgetPod, TypedWidget},
     Backend, BoxConstraints,
 };
@@ -36,11 +41,40 @@ impl<T, B: Backend> Interface<T, B> {
         self.layout_step.box_constraints = BoxConstraints {
             min: Size::default(),
             max: new_size,
-        }
+        };
+        // Viewport-relative units change along with the size
+        self.request_layout();
+    }
+
+    /// Checks every size returned during layout, reporting invalid ones in `layout_errors`
+    pub fn set_layout_validation(&mut self, validate: bool) {
+        self.layout_step.validate = validate;
+        // Cached layouts weren't checked
+        self.request_layout();
+    }
+
+    /// Lays out every widget on the next layout.
+    ///
+    /// Changes made to the data in an event ask for it through `CommonReaction::ReLayout`.
+    /// This is needed when the data or the backend changed outside of an event.
+    pub fn request_layout(&mut self) {
+        self.layout_step.request_layout()
+    }
+
+    pub fn layout_errors(&self) -> &[LayoutError] {
+        self.layout_step.errors()
     }
 
     pub fn event(&mut self, data: &mut T) {
         self.event_step.apply::<T, B, _>(&mut self.widget, data);
+        // Widgets out of the path of the event may read the data that changed
+        if self
+            .event_step
+            .drain_reactions()
+            .any(|reaction| reaction.requests_full_layout())
+        {
+            self.request_layout();
+        }
     }
 
     pub fn layout(&mut self, backend: &B, data: &T) {
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
-use math::Size;
+use math::{Insets, Size};
+use reactions::LayoutReaction;
 
 pub mod component;
 pub mod contexts;
@@ -12,7 +13,7 @@ pub mod reactions;
 pub mod steps;
 pub mod widgets;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BoxConstraints {
     pub min: Size,
     pub max: Size,
@@ -41,14 +42,14 @@ impl BoxConstraints {
         self
     }
 
-    /// Shrink min and max constraints by size
+    /// Shrink min and max constraints by the space taken by insets
     ///
-    /// The given size is also [rounded away from zero],
+    /// The space taken is also [rounded away from zero],
     /// so that the layout is aligned to integers.
     ///
     /// [rounded away from zero]: struct.Size.html#method.expand
-    pub fn shrink(&self, diff: impl Into<Size>) -> BoxConstraints {
-        let diff = diff.into().expand();
+    pub fn shrink(&self, insets: Insets) -> BoxConstraints {
+        let diff = insets.size().expand();
         let min = Size::new(
             (self.min.width - diff.width).max(0.),
             (self.min.height - diff.height).max(0.),
@@ -101,5 +102,5 @@ impl<P> From<P> for CommonPrimitive<P> {
 pub trait Backend {
     type Primitive: From<CommonPrimitive<Self::Primitive>>;
     type Event;
-    type EventReaction;
+    type EventReaction: LayoutReaction;
 }
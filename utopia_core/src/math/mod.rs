@@ -1,4 +1,4 @@
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct Size {
     pub width: f32,
     pub height: f32,
@@ -51,7 +51,7 @@ impl Size {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct Vector2 {
     pub x: f32,
     pub y: f32,
@@ -87,6 +87,121 @@ impl Vector2 {
     }
 }
 
+/// Distances from the edges of a rectangle, inwards
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Insets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Insets {
+    pub const ZERO: Insets = Insets {
+        left: 0.,
+        top: 0.,
+        right: 0.,
+        bottom: 0.,
+    };
+
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Insets {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// The same inset on every side
+    pub fn uniform(inset: f32) -> Self {
+        Insets::new(inset, inset, inset, inset)
+    }
+
+    /// `horizontal` on the left and right sides, `vertical` on the top and bottom sides
+    pub fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Insets::new(horizontal, vertical, horizontal, vertical)
+    }
+
+    /// Sum of the left and right insets
+    pub fn width(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// Sum of the top and bottom insets
+    pub fn height(&self) -> f32 {
+        self.top + self.bottom
+    }
+
+    /// Space taken by the insets on both axes
+    pub fn size(&self) -> Size {
+        Size::new(self.width(), self.height())
+    }
+
+    /// Position of the inner rectangle relative to the outer one
+    pub fn origin(&self) -> Vector2 {
+        Vector2::new(self.left, self.top)
+    }
+}
+
+impl std::ops::Add<Insets> for Insets {
+    type Output = Insets;
+
+    fn add(self, rhs: Insets) -> Self::Output {
+        Insets {
+            left: self.left + rhs.left,
+            top: self.top + rhs.top,
+            right: self.right + rhs.right,
+            bottom: self.bottom + rhs.bottom,
+        }
+    }
+}
+
+impl std::ops::Sub<Insets> for Insets {
+    type Output = Insets;
+
+    fn sub(self, rhs: Insets) -> Self::Output {
+        Insets {
+            left: self.left - rhs.left,
+            top: self.top - rhs.top,
+            right: self.right - rhs.right,
+            bottom: self.bottom - rhs.bottom,
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for Insets {
+    type Output = Insets;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Insets {
+            left: self.left * rhs,
+            top: self.top * rhs,
+            right: self.right * rhs,
+            bottom: self.bottom * rhs,
+        }
+    }
+}
+
+/// Direction in which content flows horizontally
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Horizontal position of something of the given `width` in `space`,
+    /// given its position `x` in a left-to-right layout
+    pub fn mirror(&self, x: f32, width: f32, space: f32) -> f32 {
+        match self {
+            LayoutDirection::LeftToRight => x,
+            LayoutDirection::RightToLeft => space - x - width,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Rectangle {
     pub origin: Vector2,
@@ -1,5 +1,69 @@
 pub enum CommonReaction {
     TakeFocus,
     ReDraw,
+    /// The data changed, every widget is laid out again
     ReLayout,
+    /// Only the state of the reacting widget changed, it is laid out again along with its parents
+    ReLayoutSelf,
+}
+
+/// Tells whether a reaction asks for a new layout
+pub trait LayoutReaction {
+    /// The widget pods it bubbles up through lay out their child again
+    fn requests_layout(&self) -> bool;
+    /// Every widget is laid out again, as the data they read may have changed
+    fn requests_full_layout(&self) -> bool;
+}
+
+impl LayoutReaction for () {
+    fn requests_layout(&self) -> bool {
+        false
+    }
+
+    fn requests_full_layout(&self) -> bool {
+        false
+    }
+}
+
+impl LayoutReaction for CommonReaction {
+    fn requests_layout(&self) -> bool {
+        matches!(
+            self,
+            CommonReaction::ReLayout | CommonReaction::ReLayoutSelf
+        )
+    }
+
+    fn requests_full_layout(&self) -> bool {
+        matches!(self, CommonReaction::ReLayout)
+    }
+}
+
+/// Allows a widget to intercept a specific reaction bubbling up from its children.
+///
+/// What is left of the original reaction is given back, to keep bubbling up.
+pub trait TransformReaction<Reaction>: Sized {
+    fn transform_reaction(self) -> (Option<Reaction>, Option<Self>);
+}
+
+impl<T> TransformReaction<T> for T {
+    fn transform_reaction(self) -> (Option<T>, Option<T>) {
+        (Some(self), None)
+    }
+}
+
+/// Allows a widget to react along with one of its children
+pub trait MergeReaction {
+    fn merge(self, other: Self) -> Self;
+}
+
+impl MergeReaction for () {
+    fn merge(self, _other: ()) {}
+}
+
+/// Merges two optional reactions, keeping either when the other is missing
+pub fn merge<R: MergeReaction>(first: Option<R>, second: Option<R>) -> Option<R> {
+    match (first, second) {
+        (Some(first), Some(second)) => Some(first.merge(second)),
+        (first, second) => first.or(second),
+    }
 }
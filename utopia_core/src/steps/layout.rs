@@ -1,7 +1,117 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+
 use crate::{math::Size, widgets::TypedWidget, Backend, BoxConstraints};
 
+thread_local! {
+    /// Set while a layout ignoring the cache of widget pods is in progress
+    static FORCED: Cell<bool> = const { Cell::new(false) };
+    /// Set while a validating layout is in progress
+    static VALIDATION: RefCell<Option<Validation>> = const { RefCell::new(None) };
+}
+
+/// Whether widget pods must lay out their child again, even under the same constraints
+pub(crate) fn is_forced() -> bool {
+    FORCED.with(Cell::get)
+}
+
+/// A size returned by a widget that is NaN, infinite or outside of its constraints
+#[derive(Debug, Clone)]
+pub struct LayoutError {
+    /// Type names of the widgets from the root to the offending one
+    pub path: Vec<&'static str>,
+    pub constraints: BoxConstraints,
+    pub size: Size,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} returned {:?} under {:?}",
+            self.path.join(" > "),
+            self.size,
+            self.constraints
+        )
+    }
+}
+
+#[derive(Default)]
+struct Validation {
+    path: Vec<&'static str>,
+    errors: Vec<LayoutError>,
+}
+
+/// Sizes a bit off their constraints come from rounding, not from broken layouts
+const TOLERANCE: f32 = 0.01;
+
+fn is_valid(value: f32, min: f32, max: f32) -> bool {
+    value.is_finite() && value >= min - TOLERANCE && value <= max + TOLERANCE
+}
+
+/// Closest value to `value` that is valid
+fn sanitize(value: f32, min: f32, max: f32) -> f32 {
+    let value = if value.is_nan() {
+        min
+    } else {
+        value.max(min).min(max)
+    };
+    match value.is_finite() {
+        true => value,
+        false => min,
+    }
+}
+
+/// Marks the start of the layout of a widget, when validating
+pub(crate) fn enter(type_name: &'static str) {
+    VALIDATION.with(|validation| {
+        if let Some(validation) = validation.borrow_mut().as_mut() {
+            validation.path.push(type_name);
+        }
+    })
+}
+
+/// Marks the end of the layout of a widget, when validating.
+///
+/// An invalid size is reported, and replaced by the closest valid one
+pub(crate) fn leave(bc: &BoxConstraints, size: Size) -> Size {
+    VALIDATION.with(|validation| {
+        let mut validation = validation.borrow_mut();
+        let validation = match validation.as_mut() {
+            Some(validation) => validation,
+            None => return size,
+        };
+
+        let valid = is_valid(size.width, bc.min.width, bc.max.width)
+            && is_valid(size.height, bc.min.height, bc.max.height);
+        let size = match valid {
+            true => size,
+            false => {
+                validation.errors.push(LayoutError {
+                    path: validation.path.clone(),
+                    constraints: *bc,
+                    size,
+                });
+                Size::new(
+                    sanitize(size.width, bc.min.width, bc.max.width),
+                    sanitize(size.height, bc.min.height, bc.max.height),
+                )
+            }
+        };
+        validation.path.pop();
+        size
+    })
+}
+
 pub struct LayoutStep {
     pub box_constraints: BoxConstraints,
+    /// Checks the size returned by every widget pod, see `LayoutStep::errors`
+    pub validate: bool,
+    /// Lays out every widget on the next layout, see `LayoutStep::request_layout`
+    relayout: bool,
+    errors: Vec<LayoutError>,
 }
 
 impl Default for LayoutStep {
@@ -11,6 +121,9 @@ impl Default for LayoutStep {
                 min: Size::default(),
                 max: Size::default(),
             },
+            validate: false,
+            relayout: false,
+            errors: Vec::new(),
         }
     }
 }
@@ -22,6 +135,44 @@ impl LayoutStep {
         backend: &B,
         data: &T,
     ) -> Size {
-        <TW as TypedWidget<T, B>>::layout(widget, &self.box_constraints, backend, data)
+        FORCED.with(|forced| forced.set(std::mem::take(&mut self.relayout)));
+        let size = self.validated(widget, backend, data);
+        FORCED.with(|forced| forced.set(false));
+        size
+    }
+
+    fn validated<T, B: Backend, TW: TypedWidget<T, B>>(
+        &mut self,
+        widget: &mut TW,
+        backend: &B,
+        data: &T,
+    ) -> Size {
+        if !self.validate {
+            self.errors.clear();
+            return <TW as TypedWidget<T, B>>::layout(widget, &self.box_constraints, backend, data);
+        }
+
+        VALIDATION.with(|validation| *validation.borrow_mut() = Some(Validation::default()));
+        let size = <TW as TypedWidget<T, B>>::layout(widget, &self.box_constraints, backend, data);
+        let validation = VALIDATION.with(|validation| validation.borrow_mut().take());
+        self.errors = validation
+            .map(|validation| validation.errors)
+            .unwrap_or_default();
+        size
+    }
+
+    /// Lays out every widget on the next layout, ignoring the layouts cached by widget pods.
+    ///
+    /// Widget pods otherwise only lay out their child again when given other constraints,
+    /// or when a reaction asking for a layout bubbled up through them, see `LayoutReaction`.
+    pub fn request_layout(&mut self) {
+        self.relayout = true
+    }
+
+    /// Sizes found invalid by the last validating layout.
+    ///
+    /// Widget pods that aren't laid out again keep their previous size, so each error is reported once
+    pub fn errors(&self) -> &[LayoutError] {
+        &self.errors
     }
 }
@@ -33,6 +33,26 @@ impl<T, W: TypedWidget<T, B> + Widget<T>, C: TypedController<T, W, B>, B: Backen
         TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data)
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        TypedWidget::<T, B>::baseline(&self.widget, context, data)
+    }
+
     fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
         TypedWidget::<T, B>::draw(&self.widget, origin, size, data)
     }
@@ -57,6 +57,40 @@ impl<T, U, L: Lens<T, U>, W: TypedWidget<U, B>, B: Backend> Widget<T> for LensWr
         })
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let widget = &mut self.widget;
+        self.lens.with(data, |data| {
+            TypedWidget::<U, B>::min_intrinsic_width(widget, height, context, data)
+        })
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let widget = &mut self.widget;
+        self.lens.with(data, |data| {
+            TypedWidget::<U, B>::max_intrinsic_width(widget, height, context, data)
+        })
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let widget = &mut self.widget;
+        self.lens.with(data, |data| {
+            TypedWidget::<U, B>::min_intrinsic_height(widget, width, context, data)
+        })
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let widget = &mut self.widget;
+        self.lens.with(data, |data| {
+            TypedWidget::<U, B>::max_intrinsic_height(widget, width, context, data)
+        })
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        self.lens.with(data, |data| {
+            TypedWidget::<U, B>::baseline(&self.widget, context, data)
+        })
+    }
+
     fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
         self.lens.with(data, |data| {
             TypedWidget::<U, B>::draw(&self.widget, origin, size, data)
@@ -67,6 +67,39 @@ pub trait Widget<T> {
         None
     }
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size;
+
+    /// Smallest width the widget can be laid out in without overflowing, given a `height`.
+    ///
+    /// Defaults to `max_intrinsic_width`.
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.max_intrinsic_width(height, context, data)
+    }
+
+    /// Width the widget takes when unconstrained horizontally, given a `height`.
+    ///
+    /// Defaults to `0.`, as measuring can't commit a layout.
+    fn max_intrinsic_width(&mut self, _height: f32, _context: &Self::Context, _data: &T) -> f32 {
+        0.
+    }
+
+    /// Smallest height the widget can be laid out in without overflowing, given a `width`.
+    ///
+    /// Defaults to `max_intrinsic_height`.
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.max_intrinsic_height(width, context, data)
+    }
+
+    /// Height the widget takes when unconstrained vertically, given a `width`.
+    ///
+    /// Defaults to `0.`, as measuring can't commit a layout.
+    fn max_intrinsic_height(&mut self, _width: f32, _context: &Self::Context, _data: &T) -> f32 {
+        0.
+    }
+
+    /// Distance from the top of the widget to its first baseline, once laid out
+    fn baseline(&self, _context: &Self::Context, _data: &T) -> Option<f32> {
+        None
+    }
 }
 
 impl<T> Widget<T> for () {
@@ -95,6 +128,26 @@ impl<T, W: Widget<T>> Widget<T> for Box<W> {
         self.as_mut().layout(bc, context, data)
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.as_mut().min_intrinsic_width(height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.as_mut().max_intrinsic_width(height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.as_mut().min_intrinsic_height(width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.as_mut().max_intrinsic_height(width, context, data)
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        self.as_ref().baseline(context, data)
+    }
+
     fn event(
         &mut self,
         origin: Vector2,
@@ -120,6 +173,11 @@ pub trait TypedWidget<T, B: Backend>: sealed::InnerTypedWidget<T, B> {
         event: B::Event,
     ) -> Option<B::EventReaction>;
     fn layout(&mut self, bc: &BoxConstraints, backend: &B, data: &T) -> Size;
+    fn min_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32;
+    fn max_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32;
+    fn min_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32;
+    fn max_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32;
+    fn baseline(&self, backend: &B, data: &T) -> Option<f32>;
 }
 
 impl<T, B: Backend, TW> TypedWidget<T, B> for TW
@@ -130,6 +188,26 @@ where
         <Self as sealed::InnerTypedWidget<T, B>>::layout(self, bc, backend, data)
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32 {
+        <Self as sealed::InnerTypedWidget<T, B>>::min_intrinsic_width(self, height, backend, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32 {
+        <Self as sealed::InnerTypedWidget<T, B>>::max_intrinsic_width(self, height, backend, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32 {
+        <Self as sealed::InnerTypedWidget<T, B>>::min_intrinsic_height(self, width, backend, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32 {
+        <Self as sealed::InnerTypedWidget<T, B>>::max_intrinsic_height(self, width, backend, data)
+    }
+
+    fn baseline(&self, backend: &B, data: &T) -> Option<f32> {
+        <Self as sealed::InnerTypedWidget<T, B>>::baseline(self, backend, data)
+    }
+
     fn event(
         &mut self,
         origin: Vector2,
@@ -162,6 +240,11 @@ mod sealed {
             event: B::Event,
         ) -> Option<B::EventReaction>;
         fn layout(&mut self, bc: &BoxConstraints, backend: &B, data: &T) -> Size;
+        fn min_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32;
+        fn max_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32;
+        fn min_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32;
+        fn max_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32;
+        fn baseline(&self, backend: &B, data: &T) -> Option<f32>;
     }
 
     impl<T, W, P, C, B: Backend> InnerTypedWidget<T, B> for W
@@ -177,6 +260,26 @@ mod sealed {
             <Self as Widget<T>>::layout(self, bc, context, data)
         }
 
+        fn min_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32 {
+            <Self as Widget<T>>::min_intrinsic_width(self, height, backend.provide(), data)
+        }
+
+        fn max_intrinsic_width(&mut self, height: f32, backend: &B, data: &T) -> f32 {
+            <Self as Widget<T>>::max_intrinsic_width(self, height, backend.provide(), data)
+        }
+
+        fn min_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32 {
+            <Self as Widget<T>>::min_intrinsic_height(self, width, backend.provide(), data)
+        }
+
+        fn max_intrinsic_height(&mut self, width: f32, backend: &B, data: &T) -> f32 {
+            <Self as Widget<T>>::max_intrinsic_height(self, width, backend.provide(), data)
+        }
+
+        fn baseline(&self, backend: &B, data: &T) -> Option<f32> {
+            <Self as Widget<T>>::baseline(self, backend.provide(), data)
+        }
+
         fn event(
             &mut self,
             origin: Vector2,
@@ -1,12 +1,30 @@
+use std::cell::Cell;
+
 use crate::{
     math::{Size, Vector2},
+    reactions::LayoutReaction,
+    steps::layout,
     Backend, BoxConstraints,
 };
 
 use super::{TypedWidget, Widget};
 
+thread_local! {
+    /// Origin of the widget pod handling an event, summed from the origins given by layouts
+    static LAYOUT_ORIGIN: Cell<Vector2> = const { Cell::new(Vector2::ZERO) };
+}
+
+/// Origin of the closest widget pod around the widget handling an event, as placed by layouts.
+///
+/// Unlike the origin given to `Widget::event`, it doesn't change when an ancestor scrolls
+pub fn layout_origin() -> Vector2 {
+    LAYOUT_ORIGIN.with(Cell::get)
+}
+
 pub struct WidgetPod<T, B: Backend> {
     widget: Box<dyn TypedWidget<T, B>>,
+    /// Names the widget when reporting layout errors
+    type_name: &'static str,
     state: WidgetState,
 }
 
@@ -14,6 +32,7 @@ impl<T, B: Backend> WidgetPod<T, B> {
     pub fn new<TW: TypedWidget<T, B> + 'static>(typed_widget: TW) -> Self {
         WidgetPod {
             widget: Box::new(typed_widget),
+            type_name: std::any::type_name::<TW>(),
             state: WidgetState::default(),
         }
     }
@@ -22,9 +41,19 @@ impl<T, B: Backend> WidgetPod<T, B> {
         self.state.origin = origin
     }
 
+    /// Position of the widget relative to its parent
+    pub fn origin(&self) -> Vector2 {
+        self.state.origin
+    }
+
     pub fn set_size(&mut self, size: Size) {
         self.state.size = size
     }
+
+    /// Lays out the child again on the next layout, even under the same constraints
+    pub fn request_layout(&mut self) {
+        self.state.needs_layout = true
+    }
 }
 
 impl<T, B: Backend> Widget<T> for WidgetPod<T, B> {
@@ -34,11 +63,41 @@ impl<T, B: Backend> Widget<T> for WidgetPod<T, B> {
     type Reaction = B::EventReaction;
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        let cached =
+            !self.state.needs_layout && !layout::is_forced() && self.state.constraints == Some(*bc);
+        if cached {
+            return self.state.size;
+        }
+
+        layout::enter(self.type_name);
         let size = TypedWidget::<T, B>::layout(self.widget.as_mut(), bc, context, data);
+        let size = layout::leave(bc, size);
         self.set_size(size.clone());
+        self.state.constraints = Some(*bc);
+        self.state.needs_layout = false;
         size
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(self.widget.as_mut(), height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(self.widget.as_mut(), height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(self.widget.as_mut(), width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(self.widget.as_mut(), width, context, data)
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        TypedWidget::<T, B>::baseline(self.widget.as_ref(), context, data)
+    }
+
     fn draw(&self, origin: Vector2, _size: Size, data: &T) -> Self::Primitive {
         TypedWidget::<T, B>::draw(
             self.widget.as_ref(),
@@ -55,13 +114,24 @@ impl<T, B: Backend> Widget<T> for WidgetPod<T, B> {
         data: &mut T,
         event: Self::Event,
     ) -> Option<Self::Reaction> {
-        TypedWidget::<T, B>::event(
+        let parent_origin = layout_origin();
+        LAYOUT_ORIGIN.with(|layout_origin| layout_origin.set(parent_origin + self.state.origin));
+        let reaction = TypedWidget::<T, B>::event(
             self.widget.as_mut(),
             origin + self.state.origin,
             self.state.size,
             data,
             event,
-        )
+        );
+        LAYOUT_ORIGIN.with(|layout_origin| layout_origin.set(parent_origin));
+        // Widgets on the path from the one asking for a layout up to the root are laid out again
+        if reaction
+            .as_ref()
+            .is_some_and(LayoutReaction::requests_layout)
+        {
+            self.request_layout();
+        }
+        reaction
     }
 }
 
@@ -69,4 +139,7 @@ impl<T, B: Backend> Widget<T> for WidgetPod<T, B> {
 pub struct WidgetState {
     origin: Vector2,
     size: Size,
+    /// Constraints of the last layout, skipped when given again
+    constraints: Option<BoxConstraints>,
+    needs_layout: bool,
 }
@@ -51,6 +51,19 @@ impl<U: Clone, L, LW: Lens<W, U>, W, TW: Deref<Target = W> + DerefMut, B: Backen
             _b: std::marker::PhantomData,
         }
     }
+
+    /// Copies the styled property from the data into the widget, which may size itself from it
+    fn apply_style<T>(&mut self, data: &T)
+    where
+        L: Lens<T, U>,
+    {
+        // TODO: This should probably move to a "lifecycle" handling function ?
+        let styled_prop = self.lens.with(data, |data| data.clone());
+        let widget = &mut self.widget;
+        self.lens_widget.with_mut(widget, |widget_data| {
+            *widget_data = styled_prop;
+        });
+    }
 }
 
 impl<
@@ -73,12 +86,31 @@ impl<
     }
 
     fn layout(&mut self, bc: &crate::BoxConstraints, context: &Self::Context, data: &T) -> Size {
-        // TODO: This should probably move to a "lifecycle" handling function ?
-        let styled_prop = self.lens.with(data, |data| data.clone());
-        let widget = &mut self.widget;
-        self.lens_widget.with_mut(widget, |widget_data| {
-            *widget_data = styled_prop;
-        });
-        TypedWidget::<T, B>::layout(widget, bc, context, data)
+        self.apply_style(data);
+        TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data)
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.apply_style(data);
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.apply_style(data);
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.apply_style(data);
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.apply_style(data);
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        TypedWidget::<T, B>::baseline(&self.widget, context, data)
     }
 }
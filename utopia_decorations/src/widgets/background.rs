@@ -54,4 +54,20 @@ impl<T, Color: Clone, B: Backend> Widget<T> for Background<T, Color, B> {
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
         TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data)
     }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
 }
@@ -1,5 +1,5 @@
 use utopia_core::{
-    math::{Size, Vector2},
+    math::{Insets, Size, Vector2},
     widgets::{pod::WidgetPod, TypedWidget, Widget},
     Backend, BoxConstraints,
 };
@@ -53,21 +53,50 @@ impl<T, Color: Clone, B: Backend> Widget<T> for Border<T, Color, B> {
     type Reaction = B::EventReaction;
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
-        let border_width = self.border_width as f32;
-        let double_border_width = border_width * 2.;
-        let child_bc = bc.shrink((double_border_width, double_border_width));
+        let insets = Insets::uniform(self.border_width as f32);
+        let child_bc = bc.shrink(insets);
         let child_size = TypedWidget::<T, B>::layout(&mut self.widget, &child_bc, context, data);
-        self.widget.set_origin(Vector2 {
-            x: border_width,
-            y: border_width,
-        });
+        self.widget.set_origin(insets.origin());
 
         Size {
-            width: child_size.width + double_border_width,
-            height: child_size.height + double_border_width,
+            width: child_size.width + insets.width(),
+            height: child_size.height + insets.height(),
         }
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = Insets::uniform(self.border_width as f32);
+        let height = (height - insets.height()).max(0.);
+        insets.width()
+            + TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = Insets::uniform(self.border_width as f32);
+        let height = (height - insets.height()).max(0.);
+        insets.width()
+            + TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = Insets::uniform(self.border_width as f32);
+        let width = (width - insets.width()).max(0.);
+        insets.height()
+            + TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = Insets::uniform(self.border_width as f32);
+        let width = (width - insets.width()).max(0.);
+        insets.height()
+            + TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        TypedWidget::<T, B>::baseline(&self.widget, context, data)
+            .map(|baseline| baseline + self.widget.origin().y)
+    }
+
     fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
         let border = BorderPrimitive {
             border_color: self.border_color.clone(),
@@ -43,4 +43,13 @@ impl<Img: Clone> Widget<Img> for Image<Img> {
         let size = (context.measure)(data);
         bc.constrain(size)
     }
+
+    // Images are shown at their own size
+    fn max_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &Img) -> f32 {
+        (context.measure)(data).width
+    }
+
+    fn max_intrinsic_height(&mut self, _width: f32, context: &Self::Context, data: &Img) -> f32 {
+        (context.measure)(data).height
+    }
 }
@@ -1,18 +1,81 @@
+use std::ops::{Add, Sub};
+
+use utopia_core::math::Size;
+
+/// Lengths that relative units are resolved against, provided by the backend
+#[derive(Debug, Clone)]
+pub struct LayoutUnits {
+    /// Size of the window or terminal
+    pub viewport: Size,
+    /// Font size of the current text, in pixels
+    pub em: f32,
+    /// Font size of the root text, in pixels
+    pub rem: f32,
+}
+
+impl Default for LayoutUnits {
+    fn default() -> Self {
+        LayoutUnits {
+            viewport: Size::ZERO,
+            em: 16.,
+            rem: 16.,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValueConstraint {
     Pixels(f32),
     Percent(f32),
+    /// Fraction of the viewport width
+    ViewportWidth(f32),
+    /// Fraction of the viewport height
+    ViewportHeight(f32),
+    /// Multiple of the font size of the current text
+    Em(f32),
+    /// Multiple of the font size of the root text
+    Rem(f32),
+    /// Both values added, like CSS' `calc(a + b)`
+    Sum(Box<ValueConstraint>, Box<ValueConstraint>),
+    /// The second value taken from the first, like CSS' `calc(a - b)`
+    Difference(Box<ValueConstraint>, Box<ValueConstraint>),
+    /// The smallest of both values, or the one that can be solved
+    Min(Box<ValueConstraint>, Box<ValueConstraint>),
+    /// The largest of both values, or the one that can be solved
+    Max(Box<ValueConstraint>, Box<ValueConstraint>),
     Unconstrained,
 }
 
 impl ValueConstraint {
-    pub fn solve(&self, parent_size: f32) -> Option<f32> {
+    pub fn solve(&self, parent_size: f32, units: &LayoutUnits) -> Option<f32> {
         match self {
             ValueConstraint::Percent(percent) => match parent_size.is_finite() {
                 true => Some(parent_size * percent),
                 false => None,
             },
             ValueConstraint::Pixels(pixels) => Some(*pixels),
+            ValueConstraint::ViewportWidth(fraction) => Some(units.viewport.width * fraction),
+            ValueConstraint::ViewportHeight(fraction) => Some(units.viewport.height * fraction),
+            ValueConstraint::Em(em) => Some(units.em * em),
+            ValueConstraint::Rem(rem) => Some(units.rem * rem),
+            ValueConstraint::Sum(a, b) => {
+                Some(a.solve(parent_size, units)? + b.solve(parent_size, units)?)
+            }
+            ValueConstraint::Difference(a, b) => {
+                Some(a.solve(parent_size, units)? - b.solve(parent_size, units)?)
+            }
+            ValueConstraint::Min(a, b) => {
+                match (a.solve(parent_size, units), b.solve(parent_size, units)) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                }
+            }
+            ValueConstraint::Max(a, b) => {
+                match (a.solve(parent_size, units), b.solve(parent_size, units)) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    (a, b) => a.or(b),
+                }
+            }
             ValueConstraint::Unconstrained => None,
         }
     }
@@ -25,9 +88,64 @@ impl ValueConstraint {
         ValueConstraint::Percent(percent / 100.)
     }
 
+    /// Percent of the viewport width
+    pub fn vw(percent: f32) -> Self {
+        ValueConstraint::ViewportWidth(percent / 100.)
+    }
+
+    /// Percent of the viewport height
+    pub fn vh(percent: f32) -> Self {
+        ValueConstraint::ViewportHeight(percent / 100.)
+    }
+
+    pub fn em(em: f32) -> Self {
+        ValueConstraint::Em(em)
+    }
+
+    pub fn rem(rem: f32) -> Self {
+        ValueConstraint::Rem(rem)
+    }
+
     pub fn unconstrained() -> Self {
         ValueConstraint::Unconstrained
     }
+
+    /// The smallest of `self` and `other`, like CSS' `min()`
+    pub fn min(self, other: ValueConstraint) -> Self {
+        ValueConstraint::Min(Box::new(self), Box::new(other))
+    }
+
+    /// The largest of `self` and `other`, like CSS' `max()`
+    pub fn max(self, other: ValueConstraint) -> Self {
+        ValueConstraint::Max(Box::new(self), Box::new(other))
+    }
+
+    /// Keeps `self` between `min` and `max`, like CSS' `clamp()`
+    pub fn clamp(self, min: ValueConstraint, max: ValueConstraint) -> Self {
+        self.min(max).max(min)
+    }
+}
+
+impl Add for ValueConstraint {
+    type Output = ValueConstraint;
+
+    fn add(self, other: ValueConstraint) -> Self::Output {
+        ValueConstraint::Sum(Box::new(self), Box::new(other))
+    }
+}
+
+impl Sub for ValueConstraint {
+    type Output = ValueConstraint;
+
+    fn sub(self, other: ValueConstraint) -> Self::Output {
+        ValueConstraint::Difference(Box::new(self), Box::new(other))
+    }
+}
+
+impl From<f32> for ValueConstraint {
+    fn from(pixels: f32) -> Self {
+        ValueConstraint::Pixels(pixels)
+    }
 }
 
 impl Default for ValueConstraint {
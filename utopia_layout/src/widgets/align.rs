@@ -1,4 +1,5 @@
-use utopia_core::math::{Size, Vector2};
+use utopia_core::contexts::ContextProvider;
+use utopia_core::math::{LayoutDirection, Size, Vector2};
 use utopia_core::widgets::pod::WidgetPod;
 use utopia_core::widgets::{TypedWidget, Widget};
 use utopia_core::{Backend, BoxConstraints};
@@ -25,6 +26,18 @@ impl Default for VerticalAlignment {
     }
 }
 
+impl VerticalAlignment {
+    /// Returns the position of something of the given `height` in `space`
+    pub fn position(&self, space: f32, height: f32) -> f32 {
+        match self {
+            VerticalAlignment::Top => 0.,
+            VerticalAlignment::Center => space / 2. - height / 2.,
+            VerticalAlignment::Bottom => space - height,
+        }
+    }
+}
+
+/// `Left` and `Right` are the start and end of a line, and swap in right-to-left layouts
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 pub enum HorizontalAlignment {
     Left,
@@ -38,6 +51,18 @@ impl Default for HorizontalAlignment {
     }
 }
 
+impl HorizontalAlignment {
+    /// Returns the position of something of the given `width` in `space`, following `direction`
+    pub fn position(&self, space: f32, width: f32, direction: LayoutDirection) -> f32 {
+        let start = match self {
+            HorizontalAlignment::Left => 0.,
+            HorizontalAlignment::Center => space / 2. - width / 2.,
+            HorizontalAlignment::Right => space - width,
+        };
+        direction.mirror(start, width, space)
+    }
+}
+
 impl<T, B: Backend> Align<T, B> {
     pub fn new<TW: TypedWidget<T, B> + 'static>(widget: TW) -> Self {
         Align {
@@ -58,7 +83,7 @@ impl<T, B: Backend> Align<T, B> {
     }
 }
 
-impl<T, B: Backend> Widget<T> for Align<T, B> {
+impl<T, B: Backend + ContextProvider<LayoutDirection>> Widget<T> for Align<T, B> {
     type Primitive = B::Primitive;
     type Context = B;
     type Event = B::Event;
@@ -75,22 +100,33 @@ impl<T, B: Backend> Widget<T> for Align<T, B> {
             bc_size.height = bc.max.height;
         }
 
-        let left = match self.horizontal {
-            HorizontalAlignment::Left => 0.,
-            HorizontalAlignment::Center => bc_size.width / 2. - child_size.width / 2.,
-            HorizontalAlignment::Right => bc_size.width - child_size.width,
-        };
-        let top = match self.vertical {
-            VerticalAlignment::Top => 0.,
-            VerticalAlignment::Center => bc_size.height / 2. - child_size.height / 2.,
-            VerticalAlignment::Bottom => bc_size.height - child_size.height,
-        };
+        let direction: &LayoutDirection = context.provide();
+        let left = self
+            .horizontal
+            .position(bc_size.width, child_size.width, *direction);
+        let top = self.vertical.position(bc_size.height, child_size.height);
 
         self.widget.set_origin(Vector2 { x: left, y: top });
 
         bc.constrain(bc_size)
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
     fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
         TypedWidget::<T, B>::draw(&self.widget, origin, size, data)
     }
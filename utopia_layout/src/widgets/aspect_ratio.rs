@@ -0,0 +1,138 @@
+use utopia_core::{
+    math::{Size, Vector2},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints,
+};
+
+/// A Widget that sizes its child to a given ratio between width and height.
+///
+/// Takes as much space as the constraints allow while keeping the ratio, when possible
+pub struct AspectRatio<T, B: Backend> {
+    widget: WidgetPod<T, B>,
+    /// Width divided by height
+    ratio: f32,
+}
+
+impl<T, B: Backend> AspectRatio<T, B> {
+    pub fn new<W: TypedWidget<T, B> + 'static>(widget: W, ratio: f32) -> Self {
+        AspectRatio {
+            widget: WidgetPod::new(widget),
+            ratio,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.ratio > 0. && self.ratio.is_finite()
+    }
+
+    /// Returns the biggest size of the given ratio allowed by `bc`
+    fn resolve(&self, bc: &BoxConstraints, child_width: f32) -> Size {
+        let ratio = self.ratio;
+        let mut width = if bc.is_width_bounded() {
+            bc.max.width
+        } else if bc.is_height_bounded() {
+            bc.max.height * ratio
+        } else {
+            child_width
+        };
+        let mut height = width / ratio;
+
+        if height > bc.max.height {
+            height = bc.max.height;
+            width = height * ratio;
+        }
+        if width < bc.min.width {
+            width = bc.min.width;
+            height = width / ratio;
+        }
+        if height < bc.min.height {
+            height = bc.min.height;
+            width = height * ratio;
+        }
+
+        // The ratio can't always be kept
+        bc.constrain(Size::new(width, height))
+    }
+}
+
+impl<T, B: Backend> Widget<T> for AspectRatio<T, B> {
+    type Primitive = B::Primitive;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        TypedWidget::<T, B>::draw(&self.widget, origin, size, data)
+    }
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        if !self.is_valid() {
+            return TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data);
+        }
+
+        // Without any bound, the width of the child is kept
+        let child_width = match bc.is_width_bounded() || bc.is_height_bounded() {
+            true => 0.,
+            false => TypedWidget::<T, B>::max_intrinsic_width(
+                &mut self.widget,
+                f32::INFINITY,
+                context,
+                data,
+            ),
+        };
+
+        let size = self.resolve(bc, child_width);
+        let child_bc = BoxConstraints {
+            min: size,
+            max: size,
+        };
+        TypedWidget::<T, B>::layout(&mut self.widget, &child_bc, context, data);
+        size
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.is_valid() && height.is_finite() {
+            true => height * self.ratio,
+            false => {
+                TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+            }
+        }
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.is_valid() && height.is_finite() {
+            true => height * self.ratio,
+            false => {
+                TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+            }
+        }
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.is_valid() && width.is_finite() {
+            true => width / self.ratio,
+            false => {
+                TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+            }
+        }
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.is_valid() && width.is_finite() {
+            true => width / self.ratio,
+            false => {
+                TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+            }
+        }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        TypedWidget::<T, B>::event(&mut self.widget, origin, size, data, event)
+    }
+}
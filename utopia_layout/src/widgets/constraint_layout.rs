@@ -0,0 +1,518 @@
+use cassowary::{strength, Constraint, Expression, RelationalOperator, Solver, Variable};
+use utopia_core::{
+    math::{Size, Vector2},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+
+/// Name of the layout itself in relations
+pub const PARENT: &str = "parent";
+
+/// A Widget placing named children following linear relations between their edges.
+///
+/// Relations are solved by a Cassowary constraint solver against the incoming constraints on every layout.
+/// Children prefer their own size, and required relations that can't be satisfied are ignored.
+pub struct ConstraintLayout<T, B: Backend> {
+    children: Vec<ConstraintChild<T, B>>,
+    relations: Vec<Relation>,
+}
+
+struct ConstraintChild<T, B: Backend> {
+    name: String,
+    widget: WidgetPod<T, B>,
+    size: Size,
+}
+
+/// A position or length of a child
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Width,
+    Height,
+    CenterX,
+    CenterY,
+}
+
+/// How hard the solver tries to satisfy a relation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Priority {
+    #[default]
+    Required,
+    Strong,
+    Medium,
+    Weak,
+}
+
+impl Priority {
+    fn strength(&self) -> f64 {
+        match self {
+            Priority::Required => strength::REQUIRED,
+            Priority::Strong => strength::STRONG,
+            Priority::Medium => strength::MEDIUM,
+            Priority::Weak => strength::WEAK,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+}
+
+impl From<Operator> for RelationalOperator {
+    fn from(operator: Operator) -> Self {
+        match operator {
+            Operator::LessOrEqual => RelationalOperator::LessOrEqual,
+            Operator::Equal => RelationalOperator::Equal,
+            Operator::GreaterOrEqual => RelationalOperator::GreaterOrEqual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Equation {
+    target: (String, Anchor),
+    operator: Operator,
+    source: Option<(String, Anchor)>,
+}
+
+/// Relation between anchors of children, of the form `target op source * multiplier + constant`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relation {
+    equations: Vec<Equation>,
+    multiplier: f32,
+    constant: f32,
+    priority: Priority,
+}
+
+impl Relation {
+    fn from_equations(equations: Vec<Equation>) -> Self {
+        Relation {
+            equations,
+            multiplier: 1.,
+            constant: 0.,
+            priority: Priority::default(),
+        }
+    }
+
+    /// `target`'s `target_anchor` relates to `source`'s `source_anchor`
+    pub fn new(
+        target: &str,
+        target_anchor: Anchor,
+        operator: Operator,
+        source: &str,
+        source_anchor: Anchor,
+    ) -> Self {
+        Relation::from_equations(vec![Equation {
+            target: (target.to_string(), target_anchor),
+            operator,
+            source: Some((source.to_string(), source_anchor)),
+        }])
+    }
+
+    /// `target`'s `anchor` relates to a constant
+    pub fn constant_value(target: &str, anchor: Anchor, operator: Operator, value: f32) -> Self {
+        Relation::from_equations(vec![Equation {
+            target: (target.to_string(), anchor),
+            operator,
+            source: None,
+        }])
+        .constant(value)
+    }
+
+    /// `target` ends before `other` starts, horizontally. The constant is the spacing between them
+    pub fn left_of(target: &str, other: &str) -> Self {
+        Relation::new(
+            other,
+            Anchor::Left,
+            Operator::GreaterOrEqual,
+            target,
+            Anchor::Right,
+        )
+    }
+
+    /// `target` ends before `other` starts, vertically. The constant is the spacing between them
+    pub fn above(target: &str, other: &str) -> Self {
+        Relation::new(
+            other,
+            Anchor::Top,
+            Operator::GreaterOrEqual,
+            target,
+            Anchor::Bottom,
+        )
+    }
+
+    pub fn equal_width(target: &str, source: &str) -> Self {
+        Relation::new(
+            target,
+            Anchor::Width,
+            Operator::Equal,
+            source,
+            Anchor::Width,
+        )
+    }
+
+    pub fn equal_height(target: &str, source: &str) -> Self {
+        Relation::new(
+            target,
+            Anchor::Height,
+            Operator::Equal,
+            source,
+            Anchor::Height,
+        )
+    }
+
+    /// `target` has the same center as `source` on both axes
+    pub fn centered_with(target: &str, source: &str) -> Self {
+        let equation = |anchor| Equation {
+            target: (target.to_string(), anchor),
+            operator: Operator::Equal,
+            source: Some((source.to_string(), anchor)),
+        };
+        Relation::from_equations(vec![equation(Anchor::CenterX), equation(Anchor::CenterY)])
+    }
+
+    pub fn multiplier(mut self, multiplier: f32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Added to the source, e.g. the spacing between two children
+    pub fn constant(mut self, constant: f32) -> Self {
+        self.constant = constant;
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Names of the children this relation refers to
+    fn names(&self) -> impl Iterator<Item = &str> {
+        self.equations.iter().flat_map(|equation| {
+            std::iter::once(equation.target.0.as_str())
+                .chain(equation.source.as_ref().map(|(name, _)| name.as_str()))
+        })
+    }
+}
+
+/// Solver variables describing the bounds of a child, or of the layout
+#[derive(Clone, Copy)]
+struct Bounds {
+    left: Variable,
+    top: Variable,
+    width: Variable,
+    height: Variable,
+}
+
+impl Bounds {
+    fn new() -> Self {
+        Bounds {
+            left: Variable::new(),
+            top: Variable::new(),
+            width: Variable::new(),
+            height: Variable::new(),
+        }
+    }
+
+    fn anchor(&self, anchor: Anchor) -> Expression {
+        match anchor {
+            Anchor::Left => self.left.into(),
+            Anchor::Top => self.top.into(),
+            Anchor::Width => self.width.into(),
+            Anchor::Height => self.height.into(),
+            Anchor::Right => self.left + self.width,
+            Anchor::Bottom => self.top + self.height,
+            Anchor::CenterX => self.left + self.width * 0.5,
+            Anchor::CenterY => self.top + self.height * 0.5,
+        }
+    }
+}
+
+fn add_constraint(solver: &mut Solver, expression: Expression, operator: Operator, strength: f64) {
+    // Unsatisfiable relations are ignored
+    let _ = solver.add_constraint(Constraint::new(expression, operator.into(), strength));
+}
+
+impl<T, B: Backend> Default for ConstraintLayout<T, B> {
+    fn default() -> Self {
+        ConstraintLayout {
+            children: Vec::new(),
+            relations: Vec::new(),
+        }
+    }
+}
+
+impl<T, B: Backend> ConstraintLayout<T, B> {
+    pub fn new() -> Self {
+        ConstraintLayout::default()
+    }
+
+    /// Adds a child, referred to by `name` in relations.
+    ///
+    /// Panics if `name` is `PARENT` or already taken
+    pub fn add<TW: TypedWidget<T, B> + 'static>(mut self, name: &str, widget: TW) -> Self {
+        assert!(
+            name != PARENT,
+            "`{}` names the ConstraintLayout itself, it can't name a child",
+            PARENT
+        );
+        assert!(
+            self.find(name).is_none(),
+            "a child of the ConstraintLayout is already named `{}`",
+            name
+        );
+        self.children.push(ConstraintChild {
+            name: name.to_string(),
+            widget: WidgetPod::new(widget),
+            size: Size::default(),
+        });
+        self
+    }
+
+    /// Adds a relation between children added before, or `PARENT`.
+    ///
+    /// Panics if it names an unknown child
+    pub fn relation(mut self, relation: Relation) -> Self {
+        for name in relation.names() {
+            assert!(
+                name == PARENT || self.find(name).is_some(),
+                "no child named `{}` in ConstraintLayout",
+                name
+            );
+        }
+        self.relations.push(relation);
+        self
+    }
+
+    /// Index of the child named `name`
+    fn find(&self, name: &str) -> Option<usize> {
+        self.children.iter().position(|child| child.name == name)
+    }
+
+    /// Solves the bounds of the layout and of its children under `bc`, without laying them out
+    fn solve(
+        &mut self,
+        bc: &BoxConstraints,
+        context: &B,
+        data: &T,
+    ) -> (Solver, Bounds, Vec<Bounds>) {
+        let mut solver = Solver::new();
+        let required = strength::REQUIRED;
+
+        // Step 1 : The layout fills its parent if bounded, and fits its children otherwise
+        let parent = Bounds::new();
+        add_constraint(&mut solver, parent.left.into(), Operator::Equal, required);
+        add_constraint(&mut solver, parent.top.into(), Operator::Equal, required);
+        for (variable, min, max) in [
+            (parent.width, bc.min.width, bc.max.width),
+            (parent.height, bc.min.height, bc.max.height),
+        ]
+        .iter()
+        {
+            let min = *min as f64;
+            add_constraint(
+                &mut solver,
+                *variable - min,
+                Operator::GreaterOrEqual,
+                required,
+            );
+            if max.is_finite() {
+                let max = *max as f64;
+                add_constraint(
+                    &mut solver,
+                    *variable - max,
+                    Operator::LessOrEqual,
+                    required,
+                );
+                add_constraint(
+                    &mut solver,
+                    *variable - max,
+                    Operator::Equal,
+                    strength::WEAK,
+                );
+            } else {
+                add_constraint(
+                    &mut solver,
+                    *variable - min,
+                    Operator::Equal,
+                    strength::WEAK,
+                );
+            }
+        }
+
+        // Step 2 : Children are inside the layout, and prefer their intrinsic size
+        let bounds: Vec<Bounds> = self
+            .children
+            .iter_mut()
+            .map(|child| {
+                let widget = &mut child.widget;
+                let width =
+                    TypedWidget::<T, B>::max_intrinsic_width(widget, bc.max.height, context, data)
+                        .min(bc.max.width);
+                let height =
+                    TypedWidget::<T, B>::max_intrinsic_height(widget, width, context, data)
+                        .min(bc.max.height);
+                let bounds = Bounds::new();
+                let (width, height) = (width as f64, height as f64);
+                add_constraint(
+                    &mut solver,
+                    bounds.left.into(),
+                    Operator::GreaterOrEqual,
+                    required,
+                );
+                add_constraint(
+                    &mut solver,
+                    bounds.top.into(),
+                    Operator::GreaterOrEqual,
+                    required,
+                );
+                add_constraint(
+                    &mut solver,
+                    bounds.width.into(),
+                    Operator::GreaterOrEqual,
+                    required,
+                );
+                add_constraint(
+                    &mut solver,
+                    bounds.height.into(),
+                    Operator::GreaterOrEqual,
+                    required,
+                );
+                let right = bounds.anchor(Anchor::Right) - parent.width;
+                let bottom = bounds.anchor(Anchor::Bottom) - parent.height;
+                add_constraint(&mut solver, right, Operator::LessOrEqual, required);
+                add_constraint(&mut solver, bottom, Operator::LessOrEqual, required);
+                add_constraint(
+                    &mut solver,
+                    bounds.width - width,
+                    Operator::Equal,
+                    strength::MEDIUM,
+                );
+                add_constraint(
+                    &mut solver,
+                    bounds.height - height,
+                    Operator::Equal,
+                    strength::MEDIUM,
+                );
+                bounds
+            })
+            .collect();
+
+        // Step 3 : Add the relations between children, whose names are checked when added
+        let bounds_of = |name: &str| match name {
+            PARENT => parent,
+            name => bounds[self.find(name).expect("relations only name known children")],
+        };
+        for relation in self.relations.iter() {
+            for equation in relation.equations.iter() {
+                let target = bounds_of(&equation.target.0).anchor(equation.target.1);
+                let source = match &equation.source {
+                    Some((name, anchor)) => {
+                        bounds_of(name).anchor(*anchor) * relation.multiplier as f64
+                    }
+                    None => Expression::from_constant(0.),
+                };
+                let expression = target - source - relation.constant as f64;
+                add_constraint(
+                    &mut solver,
+                    expression,
+                    equation.operator,
+                    relation.priority.strength(),
+                );
+            }
+        }
+
+        (solver, parent, bounds)
+    }
+}
+
+impl<T, B: Backend> Widget<T> for ConstraintLayout<T, B>
+where
+    B::Event: Clone,
+{
+    type Primitive = CommonPrimitive<B::Primitive>;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        let (solver, parent, bounds) = self.solve(bc, context, data);
+
+        // Step 4 : Layout children in their solved bounds
+        for (child, bounds) in self.children.iter_mut().zip(bounds.iter()) {
+            let size = Size::new(
+                solver.get_value(bounds.width).max(0.) as f32,
+                solver.get_value(bounds.height).max(0.) as f32,
+            );
+            let child_bc = BoxConstraints {
+                min: size,
+                max: size,
+            };
+            child.size = TypedWidget::<T, B>::layout(&mut child.widget, &child_bc, context, data);
+            child.widget.set_origin(Vector2::new(
+                solver.get_value(bounds.left) as f32,
+                solver.get_value(bounds.top) as f32,
+            ));
+        }
+
+        bc.constrain(Size::new(
+            solver.get_value(parent.width) as f32,
+            solver.get_value(parent.height) as f32,
+        ))
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        Widget::<T>::max_intrinsic_width(self, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let bc = BoxConstraints {
+            min: Size::ZERO,
+            max: Size::new(f32::INFINITY, height),
+        };
+        let (solver, parent, _) = self.solve(&bc, context, data);
+        solver.get_value(parent.width) as f32
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        Widget::<T>::max_intrinsic_height(self, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let bc = BoxConstraints {
+            min: Size::ZERO,
+            max: Size::new(width, f32::INFINITY),
+        };
+        let (solver, parent, _) = self.solve(&bc, context, data);
+        solver.get_value(parent.height) as f32
+    }
+
+    fn draw(&self, origin: Vector2, _size: Size, data: &T) -> Self::Primitive {
+        let children = self
+            .children
+            .iter()
+            .map(|child| TypedWidget::<T, B>::draw(&child.widget, origin, child.size, data))
+            .collect();
+
+        CommonPrimitive::Group { children }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        _size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        self.children.iter_mut().find_map(|child| {
+            TypedWidget::<T, B>::event(&mut child.widget, origin, child.size, data, event.clone())
+        })
+    }
+}
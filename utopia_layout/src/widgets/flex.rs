@@ -1,33 +1,189 @@
-use std::collections::HashMap;
+use std::ops::Range;
 
 use utopia_core::{
-    math::{Size, Vector2},
+    contexts::ContextProvider,
+    math::{LayoutDirection, Size, Vector2},
     Backend, BoxConstraints, CommonPrimitive,
 };
 
 use utopia_core::widgets::{pod::WidgetPod, TypedWidget, Widget};
 
+use crate::{LayoutUnits, ValueConstraint};
+
 /// A Widget that implements a Flex layout algorithm.
 pub struct Flex<T, B: Backend> {
     children: Vec<FlexChild<T, B>>,
     computed_sizes: Vec<Size>,
     flex_direction: FlexDirection,
+    main_axis_alignment: MainAxisAlignment,
+    cross_axis_alignment: CrossAxisAlignment,
+    wrap: FlexWrap,
+    /// Space between two children of a line
+    gap: f32,
+    /// Space between two lines, when wrapping
+    line_spacing: f32,
 }
 
 #[derive(Clone, Copy)]
-enum FlexDirection {
+pub(crate) enum FlexDirection {
     Row,
     Column,
 }
 
+impl FlexDirection {
+    pub(crate) fn main(&self, size: Size) -> f32 {
+        match self {
+            FlexDirection::Row => size.width,
+            FlexDirection::Column => size.height,
+        }
+    }
+
+    pub(crate) fn cross(&self, size: Size) -> f32 {
+        match self {
+            FlexDirection::Row => size.height,
+            FlexDirection::Column => size.width,
+        }
+    }
+
+    pub(crate) fn size(&self, main: f32, cross: f32) -> Size {
+        match self {
+            FlexDirection::Row => Size::new(main, cross),
+            FlexDirection::Column => Size::new(cross, main),
+        }
+    }
+
+    pub(crate) fn vector(&self, main: f32, cross: f32) -> Vector2 {
+        match self {
+            FlexDirection::Row => Vector2::new(main, cross),
+            FlexDirection::Column => Vector2::new(cross, main),
+        }
+    }
+}
+
+/// How children are distributed along the main axis
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+pub enum MainAxisAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// Free space is put between children
+    SpaceBetween,
+    /// Free space is put around children, halved at both ends
+    SpaceAround,
+    /// Free space is put evenly between children and at both ends
+    SpaceEvenly,
+}
+
+impl MainAxisAlignment {
+    /// Returns the space before the first child and the space between children
+    fn spacing(&self, free_space: f32, count: usize) -> (f32, f32) {
+        let count = count as f32;
+        match self {
+            MainAxisAlignment::Start => (0., 0.),
+            MainAxisAlignment::Center => (free_space / 2., 0.),
+            MainAxisAlignment::End => (free_space, 0.),
+            MainAxisAlignment::SpaceBetween if count > 1. => (0., free_space / (count - 1.)),
+            MainAxisAlignment::SpaceBetween => (0., 0.),
+            MainAxisAlignment::SpaceAround if count > 0. => {
+                (free_space / count / 2., free_space / count)
+            }
+            MainAxisAlignment::SpaceAround => (0., 0.),
+            MainAxisAlignment::SpaceEvenly => {
+                (free_space / (count + 1.), free_space / (count + 1.))
+            }
+        }
+    }
+}
+
+/// How children are placed along the cross axis
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+pub enum CrossAxisAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// Children are laid out again to fill the cross axis
+    Stretch,
+    /// Children of a row are aligned on their first baseline.
+    ///
+    /// Behaves like `Start` in a column, and for children that don't report a baseline
+    Baseline,
+}
+
+impl CrossAxisAlignment {
+    /// Returns the position of a child on a line of the given cross size
+    fn position(&self, cross: f32, child_cross: f32) -> f32 {
+        match self {
+            CrossAxisAlignment::Start
+            | CrossAxisAlignment::Stretch
+            | CrossAxisAlignment::Baseline => 0.,
+            CrossAxisAlignment::Center => (cross - child_cross) / 2.,
+            CrossAxisAlignment::End => cross - child_cross,
+        }
+    }
+}
+
+/// Whether children overflowing the main axis start a new line
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FlexWrap {
+    #[default]
+    NoWrap,
+    Wrap,
+}
+
 struct FlexChild<T, B: Backend> {
     pub widget: WidgetPod<T, B>,
     pub flex_option: FlexOption,
 }
 
-enum FlexOption {
-    NonFlex,
-    Flex(u8),
+/// How a child grows or shrinks along the main axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlexOption {
+    /// Share of the free space the child grows by
+    pub grow: f32,
+    /// Share of the overflow the child shrinks by, weighted by its basis
+    pub shrink: f32,
+    /// Main size of the child before growing or shrinking.
+    ///
+    /// `Unconstrained` uses the size of its content
+    pub basis: ValueConstraint,
+}
+
+impl Default for FlexOption {
+    fn default() -> Self {
+        FlexOption {
+            grow: 0.,
+            shrink: 1.,
+            basis: ValueConstraint::Unconstrained,
+        }
+    }
+}
+
+impl FlexOption {
+    /// A child that only takes the given share of the free space
+    pub fn flex(grow: f32) -> Self {
+        FlexOption {
+            grow,
+            basis: ValueConstraint::Pixels(0.),
+            ..FlexOption::default()
+        }
+    }
+
+    pub fn grow(mut self, grow: f32) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    pub fn basis(mut self, basis: ValueConstraint) -> Self {
+        self.basis = basis;
+        self
+    }
 }
 
 impl<T, B: Backend> Flex<T, B> {
@@ -36,6 +192,11 @@ impl<T, B: Backend> Flex<T, B> {
             children: Vec::default(),
             computed_sizes: Vec::default(),
             flex_direction: FlexDirection::Row,
+            main_axis_alignment: MainAxisAlignment::default(),
+            cross_axis_alignment: CrossAxisAlignment::default(),
+            wrap: FlexWrap::default(),
+            gap: 0.,
+            line_spacing: 0.,
         }
     }
 
@@ -44,34 +205,112 @@ impl<T, B: Backend> Flex<T, B> {
             children: Vec::default(),
             computed_sizes: Vec::default(),
             flex_direction: FlexDirection::Column,
+            main_axis_alignment: MainAxisAlignment::default(),
+            cross_axis_alignment: CrossAxisAlignment::default(),
+            wrap: FlexWrap::default(),
+            gap: 0.,
+            line_spacing: 0.,
         }
     }
 
-    pub fn add<TW: TypedWidget<T, B> + 'static>(mut self, widget: TW) -> Self {
-        self.children.push(FlexChild {
-            widget: WidgetPod::new(widget),
-            flex_option: FlexOption::NonFlex,
-        });
-        self.computed_sizes.push(Size::default());
+    pub fn main_axis_alignment(mut self, main_axis_alignment: MainAxisAlignment) -> Self {
+        self.main_axis_alignment = main_axis_alignment;
+        self
+    }
+
+    pub fn cross_axis_alignment(mut self, cross_axis_alignment: CrossAxisAlignment) -> Self {
+        self.cross_axis_alignment = cross_axis_alignment;
+        self
+    }
+
+    /// Breaks children into several lines when the main axis is bounded.
+    ///
+    /// Each line is aligned on its own. Flexible children share the free space of their line.
+    pub fn wrap(mut self, wrap: FlexWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Space between two children of a line
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_spacing = line_spacing;
         self
     }
 
-    pub fn add_flex<TW: TypedWidget<T, B> + 'static>(
+    pub fn add<TW: TypedWidget<T, B> + 'static>(self, widget: TW) -> Self {
+        self.add_with_option(widget, FlexOption::default())
+    }
+
+    pub fn add_flex<TW: TypedWidget<T, B> + 'static>(self, widget: TW, flex_factor: f32) -> Self {
+        self.add_with_option(widget, FlexOption::flex(flex_factor))
+    }
+
+    pub fn add_with_option<TW: TypedWidget<T, B> + 'static>(
         mut self,
         widget: TW,
-        flex_factor: u8,
+        flex_option: FlexOption,
     ) -> Self {
         self.children.push(FlexChild {
             widget: WidgetPod::new(widget),
-            flex_option: FlexOption::Flex(flex_factor),
+            flex_option,
         });
         self.computed_sizes.push(Size::default());
         self
     }
+
+    /// Main size taken by the children of a line, gaps included
+    fn line_main(&self, line: &Range<usize>) -> f32 {
+        self.computed_sizes[line.clone()]
+            .iter()
+            .map(|size| self.flex_direction.main(*size))
+            .sum::<f32>()
+            + self.gap * line.len().saturating_sub(1) as f32
+    }
+
+    /// Intrinsic width of the flex if `width`, or its intrinsic height otherwise, given its size on the other axis
+    fn intrinsic(&mut self, width: bool, min: bool, other: f32, context: &B, data: &T) -> f32 {
+        let along_main = width == matches!(self.flex_direction, FlexDirection::Row);
+        // A wrapped flex can put every child on its own line
+        let sum = along_main && !(min && self.wrap == FlexWrap::Wrap);
+        let gaps = self.gap * self.children.len().saturating_sub(1) as f32;
+        // The main size of each child is unknown when measuring the cross axis
+        let other = match along_main {
+            true => other,
+            false => f32::INFINITY,
+        };
+
+        let sizes = self.children.iter_mut().map(|child| {
+            let widget = &mut child.widget;
+            match (width, min) {
+                (true, true) => {
+                    TypedWidget::<T, B>::min_intrinsic_width(widget, other, context, data)
+                }
+                (true, false) => {
+                    TypedWidget::<T, B>::max_intrinsic_width(widget, other, context, data)
+                }
+                (false, true) => {
+                    TypedWidget::<T, B>::min_intrinsic_height(widget, other, context, data)
+                }
+                (false, false) => {
+                    TypedWidget::<T, B>::max_intrinsic_height(widget, other, context, data)
+                }
+            }
+        });
+        match sum {
+            true => sizes.sum::<f32>() + gaps,
+            false => sizes.fold(0., f32::max),
+        }
+    }
 }
 
-impl<T, B: Backend> Widget<T> for Flex<T, B>
+impl<T, B> Widget<T> for Flex<T, B>
 where
+    B: Backend + ContextProvider<LayoutUnits> + ContextProvider<LayoutDirection>,
     B::Event: Clone,
 {
     type Primitive = CommonPrimitive<B::Primitive>;
@@ -81,140 +320,228 @@ where
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
         let flex_direction = self.flex_direction;
+        let space = flex_direction.main(bc.max);
+        let cross_space = flex_direction.cross(bc.max);
+        let wrap = self.wrap == FlexWrap::Wrap && space.is_finite();
 
-        // Step 1 : Layout inflexible children
+        // Step 1 : Layout children at their basis
+        let units: &LayoutUnits = context.provide();
         let loosened = bc.loosen();
-        let loosened = match flex_direction {
-            FlexDirection::Row => loosened.unbound_x(),
-            FlexDirection::Column => loosened.unbound_y(),
+        let loosened = match (wrap, flex_direction) {
+            // A wrapped child is no bigger than a line
+            (true, _) => loosened,
+            (false, FlexDirection::Row) => loosened.unbound_x(),
+            (false, FlexDirection::Column) => loosened.unbound_y(),
         };
-
-        let inflexible_children: Vec<(usize, Size)> = self
+        let bases: Vec<f32> = self
             .children
             .iter_mut()
-            .enumerate()
-            .filter_map(|(index, child)| match child.flex_option {
-                FlexOption::NonFlex => Some((
-                    index,
-                    TypedWidget::<T, B>::layout(&mut child.widget, &loosened, context, data),
-                )),
-                FlexOption::Flex(_) => None,
+            .zip(self.computed_sizes.iter_mut())
+            .map(|(child, computed_size)| {
+                // Without a bound, there is no free space to grow into, so growing children
+                // take at least the size of their content
+                let grows_unbounded = !space.is_finite() && child.flex_option.grow > 0.;
+                match child.flex_option.basis.solve(space, units) {
+                    Some(basis) if !grows_unbounded => basis.max(0.),
+                    basis => {
+                        *computed_size = TypedWidget::<T, B>::layout(
+                            &mut child.widget,
+                            &loosened,
+                            context,
+                            data,
+                        );
+                        flex_direction.main(*computed_size).max(basis.unwrap_or(0.))
+                    }
+                }
             })
             .collect();
 
-        // Step 2 : Compute free space
-        let space = match flex_direction {
-            FlexDirection::Row => bc.max.width,
-            FlexDirection::Column => bc.max.height,
-        };
-        let sum_inflexible_children: f32 = inflexible_children
-            .iter()
-            .map(|(_index, size)| match flex_direction {
-                FlexDirection::Row => size.width,
-                FlexDirection::Column => size.height,
-            })
-            .sum();
-        let free_space = space - sum_inflexible_children;
-        let flex_factor_sum = self
-            .children
-            .iter()
-            .filter_map(|child| match child.flex_option {
-                FlexOption::Flex(flex) => Some(flex),
-                FlexOption::NonFlex => None,
-            })
-            .sum::<u8>() as f32;
-        let space_per_flex = free_space / flex_factor_sum;
+        // Step 2 : Break children into lines
+        let mut lines: Vec<Range<usize>> = Vec::new();
+        let mut start = 0;
+        let mut used = 0.;
+        for (index, basis) in bases.iter().enumerate() {
+            if wrap && index > start && used + self.gap + basis > space {
+                lines.push(start..index);
+                start = index;
+                used = 0.;
+            }
+            if index > start {
+                used += self.gap;
+            }
+            used += basis;
+        }
+        if start < bases.len() {
+            lines.push(start..bases.len());
+        }
 
-        // Step 3 : Compute flexible children
-        let flexible_children: Vec<(usize, Size)> = self
-            .children
-            .iter_mut()
-            .enumerate()
-            .filter_map(|(index, child)| match child.flex_option {
-                FlexOption::Flex(factor) => {
-                    let factor = factor as f32;
-                    let constraint = match flex_direction {
-                        FlexDirection::Row => BoxConstraints {
-                            min: Size {
-                                width: factor * space_per_flex,
-                                height: 0.,
-                            },
-                            max: Size {
-                                width: factor * space_per_flex,
-                                height: bc.max.height,
-                            },
-                        },
-                        FlexDirection::Column => BoxConstraints {
-                            min: Size {
-                                width: 0.,
-                                height: factor * space_per_flex,
-                            },
-                            max: Size {
-                                width: bc.max.width,
-                                height: factor * space_per_flex,
-                            },
-                        },
+        // Step 3 : Grow or shrink the children of each line
+        let mut line_crosses = Vec::with_capacity(lines.len());
+        let align_baselines = self.cross_axis_alignment == CrossAxisAlignment::Baseline
+            && matches!(flex_direction, FlexDirection::Row);
+        let mut baseline_offsets: Vec<Option<f32>> = vec![None; self.children.len()];
+        for line in lines.iter() {
+            let used = bases[line.clone()].iter().sum::<f32>()
+                + self.gap * line.len().saturating_sub(1) as f32;
+            let free_space = match space.is_finite() {
+                true => space - used,
+                false => 0.,
+            };
+            let options = self.children[line.clone()]
+                .iter()
+                .zip(bases[line.clone()].iter())
+                .map(|(child, basis)| (&child.flex_option, *basis));
+            let grow_sum: f32 = options.clone().map(|(option, _)| option.grow).sum();
+            let shrink_sum: f32 = options.map(|(option, basis)| option.shrink * basis).sum();
+
+            for index in line.clone() {
+                let child = &mut self.children[index];
+                let option = &child.flex_option;
+                let basis = bases[index];
+                let main = if free_space > 0. && grow_sum > 0. {
+                    basis + free_space * option.grow / grow_sum
+                } else if free_space < 0. && shrink_sum > 0. {
+                    (basis + free_space * option.shrink * basis / shrink_sum).max(0.)
+                } else {
+                    basis
+                };
+
+                // Children sized by their content keep their layout when it still fits
+                let measured = option.basis == ValueConstraint::Unconstrained
+                    && (main - flex_direction.main(self.computed_sizes[index])).abs()
+                        < f32::EPSILON;
+                if !measured {
+                    let constraint = BoxConstraints {
+                        min: flex_direction.size(main, 0.),
+                        max: flex_direction.size(main, cross_space),
                     };
-                    Some((
-                        index,
-                        TypedWidget::<T, B>::layout(&mut child.widget, &constraint, context, data),
-                    ))
+                    self.computed_sizes[index] =
+                        TypedWidget::<T, B>::layout(&mut child.widget, &constraint, context, data);
                 }
-                FlexOption::NonFlex => None,
-            })
-            .collect();
+            }
 
-        // Update all computed sizes
-        inflexible_children
-            .iter()
-            .chain(flexible_children.iter())
-            .for_each(|(index, size)| {
-                self.computed_sizes[*index] = *size;
-            });
-
-        let min = match flex_direction {
-            FlexDirection::Row => bc.min.height,
-            FlexDirection::Column => bc.min.width,
-        };
-        let liner = inflexible_children
-            .iter()
-            .chain(flexible_children.iter())
-            .map(|(_index, size)| match flex_direction {
-                FlexDirection::Row => size.height.ceil() as u32,
-                FlexDirection::Column => size.width.ceil() as u32,
-            })
-            .max()
-            .unwrap_or_default()
-            .max(min as u32) as f32;
-        let index_and_size: HashMap<usize, Size> = inflexible_children
-            .into_iter()
-            .chain(flexible_children.into_iter())
-            .collect();
+            let mut line_cross = self.computed_sizes[line.clone()]
+                .iter()
+                .map(|size| flex_direction.cross(*size))
+                .fold(0., f32::max);
 
-        // Step 4: Position children
-        let mut position = Vector2::ZERO;
-        self.children
-            .iter_mut()
-            .enumerate()
-            .for_each(|(index, child)| {
-                child.widget.set_origin(position);
-                let size = index_and_size[&index];
-                match flex_direction {
-                    FlexDirection::Row => position.x += size.width,
-                    FlexDirection::Column => position.y += size.height,
+            // Children are moved down so that their baselines match the lowest one
+            if align_baselines {
+                let baselines: Vec<Option<f32>> = self.children[line.clone()]
+                    .iter()
+                    .map(|child| TypedWidget::<T, B>::baseline(&child.widget, context, data))
+                    .collect();
+                let line_baseline = baselines.iter().flatten().fold(0., |a: f32, b| a.max(*b));
+                for (index, baseline) in line.clone().zip(baselines) {
+                    if let Some(baseline) = baseline {
+                        let offset = line_baseline - baseline;
+                        baseline_offsets[index] = Some(offset);
+                        line_cross = line_cross.max(offset + self.computed_sizes[index].height);
+                    }
                 }
-            });
+            }
+            if !wrap {
+                line_cross = line_cross.max(flex_direction.cross(bc.min));
+            }
+
+            // Stretched children are laid out again, filling the cross size of their line
+            if self.cross_axis_alignment == CrossAxisAlignment::Stretch {
+                if !wrap && cross_space.is_finite() {
+                    line_cross = cross_space;
+                }
+                for index in line.clone() {
+                    let main = flex_direction.main(self.computed_sizes[index]);
+                    let tight = flex_direction.size(main, line_cross);
+                    let constraint = BoxConstraints {
+                        min: tight,
+                        max: tight,
+                    };
+                    self.computed_sizes[index] = TypedWidget::<T, B>::layout(
+                        &mut self.children[index].widget,
+                        &constraint,
+                        context,
+                        data,
+                    );
+                }
+            }
+            line_crosses.push(line_cross);
+        }
+
+        // Step 4: Position children, line by line
+        let children_main = lines
+            .iter()
+            .map(|line| self.line_main(line))
+            .fold(0., f32::max);
+        let main = match self.main_axis_alignment {
+            MainAxisAlignment::Start => children_main,
+            _ if space.is_finite() => space.max(children_main),
+            _ => children_main,
+        };
+        let cross = line_crosses.iter().sum::<f32>()
+            + self.line_spacing * lines.len().saturating_sub(1) as f32;
+        let size = bc.constrain(flex_direction.size(main, cross));
+        let main = flex_direction.main(size);
+        if !wrap {
+            // A single line spans the whole cross axis
+            if let Some(line_cross) = line_crosses.first_mut() {
+                *line_cross = flex_direction.cross(size);
+            }
+        }
 
-        match flex_direction {
-            FlexDirection::Row => bc.constrain(Size {
-                width: position.x,
-                height: liner,
-            }),
-            FlexDirection::Column => bc.constrain(Size {
-                width: liner,
-                height: position.y,
-            }),
+        // Rows flow from the right in right-to-left layouts
+        let direction: &LayoutDirection = context.provide();
+        let direction = match flex_direction {
+            FlexDirection::Row => *direction,
+            FlexDirection::Column => LayoutDirection::LeftToRight,
+        };
+        let mut cross_position = 0.;
+        for (line, line_cross) in lines.iter().zip(line_crosses.iter()) {
+            let free_space = (main - self.line_main(line)).max(0.);
+            let (leading, between) = self.main_axis_alignment.spacing(free_space, line.len());
+            let mut position = leading;
+            for index in line.clone() {
+                let child_size = self.computed_sizes[index];
+                let child_cross = match baseline_offsets[index] {
+                    Some(offset) => offset,
+                    None => self
+                        .cross_axis_alignment
+                        .position(*line_cross, flex_direction.cross(child_size)),
+                };
+                let child_main = flex_direction.main(child_size);
+                let child_position = direction.mirror(position, child_main, main);
+                self.children[index].widget.set_origin(
+                    flex_direction.vector(child_position, cross_position + child_cross),
+                );
+                position += child_main + between + self.gap;
+            }
+            cross_position += line_cross + self.line_spacing;
         }
+
+        size
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(true, true, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(true, false, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(false, true, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(false, false, width, context, data)
+    }
+
+    /// The first baseline of the first child that has one
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        self.children.iter().find_map(|child| {
+            TypedWidget::<T, B>::baseline(&child.widget, context, data)
+                .map(|baseline| baseline + child.widget.origin().y)
+        })
     }
 
     fn draw(&self, origin: Vector2, _size: Size, data: &T) -> Self::Primitive {
@@ -252,3 +579,242 @@ where
             .next()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use utopia_core::{
+        contexts::ContextProvider,
+        controllers::click::{Click, MouseButton, MouseClickEvent},
+        interface::Interface,
+        lens::Field,
+        math::{LayoutDirection, Size, Vector2},
+        reactions::CommonReaction,
+        widgets::{CoreExt, Widget},
+        Backend, BoxConstraints, CommonPrimitive,
+    };
+
+    use super::{Flex, FlexOption, FlexWrap, MainAxisAlignment};
+    use crate::{
+        widgets::visibility::{Visibility, VisibilityState},
+        LayoutUnits,
+    };
+
+    #[derive(Default)]
+    struct TestBackend {
+        units: LayoutUnits,
+        direction: LayoutDirection,
+    }
+
+    /// Origin and size of every drawn `Fixed`
+    struct TestPrimitive(Vec<(Vector2, Size)>);
+
+    impl From<CommonPrimitive<TestPrimitive>> for TestPrimitive {
+        fn from(primitive: CommonPrimitive<TestPrimitive>) -> Self {
+            match primitive {
+                CommonPrimitive::Group { children } => {
+                    TestPrimitive(children.into_iter().flat_map(|child| child.0).collect())
+                }
+                CommonPrimitive::Specific(primitive) => *primitive,
+                _ => TestPrimitive(Vec::new()),
+            }
+        }
+    }
+
+    impl Backend for TestBackend {
+        type Primitive = TestPrimitive;
+        type Event = MouseClickEvent;
+        type EventReaction = CommonReaction;
+    }
+
+    impl ContextProvider<()> for TestBackend {
+        fn provide(&self) -> &() {
+            &()
+        }
+    }
+
+    impl ContextProvider<LayoutUnits> for TestBackend {
+        fn provide(&self) -> &LayoutUnits {
+            &self.units
+        }
+    }
+
+    impl ContextProvider<LayoutDirection> for TestBackend {
+        fn provide(&self) -> &LayoutDirection {
+            &self.direction
+        }
+    }
+
+    /// A widget that wants to be `width` by `height`
+    struct Fixed(f32, f32);
+
+    impl<T> Widget<T> for Fixed {
+        type Primitive = TestPrimitive;
+        type Context = ();
+        type Event = MouseClickEvent;
+        type Reaction = CommonReaction;
+
+        fn draw(&self, origin: Vector2, size: Size, _data: &T) -> TestPrimitive {
+            TestPrimitive(vec![(origin, size)])
+        }
+
+        fn layout(&mut self, bc: &BoxConstraints, _context: &(), _data: &T) -> Size {
+            bc.constrain(Size::new(self.0, self.1))
+        }
+
+        fn max_intrinsic_width(&mut self, _height: f32, _context: &(), _data: &T) -> f32 {
+            self.0
+        }
+
+        fn max_intrinsic_height(&mut self, _width: f32, _context: &(), _data: &T) -> f32 {
+            self.1
+        }
+    }
+
+    /// Lays out `flex` under `max`, returning its size then the origin and size of every child
+    fn layout(flex: &mut Flex<(), TestBackend>, max: Size) -> (Size, Vec<(Vector2, Size)>) {
+        let bc = BoxConstraints {
+            min: Size::ZERO,
+            max,
+        };
+        let size = Widget::<()>::layout(flex, &bc, &TestBackend::default(), &());
+        let children = flex
+            .children
+            .iter()
+            .zip(flex.computed_sizes.iter())
+            .map(|(child, size)| (child.widget.origin(), *size))
+            .collect();
+        (size, children)
+    }
+
+    #[test]
+    fn grow_splits_free_space() {
+        let mut flex = Flex::row()
+            .add_with_option(Fixed(10., 10.), FlexOption::default().grow(1.))
+            .add_with_option(Fixed(10., 10.), FlexOption::default().grow(3.));
+        let (size, children) = layout(&mut flex, Size::new(100., 50.));
+
+        assert_eq!(size.width, 100.);
+        assert_eq!(children[0], (Vector2::new(0., 0.), Size::new(30., 10.)));
+        assert_eq!(children[1], (Vector2::new(30., 0.), Size::new(70., 10.)));
+    }
+
+    #[test]
+    fn shrink_on_overflow() {
+        let mut flex = Flex::row()
+            .add(Fixed(40., 10.))
+            .add_with_option(Fixed(40., 10.), FlexOption::default().shrink(2.));
+        let (size, children) = layout(&mut flex, Size::new(50., 50.));
+
+        assert_eq!(size.width, 50.);
+        assert_eq!(children[0], (Vector2::new(0., 0.), Size::new(30., 10.)));
+        assert_eq!(children[1], (Vector2::new(30., 0.), Size::new(20., 10.)));
+    }
+
+    #[test]
+    fn flex_without_bound() {
+        let mut flex = Flex::row()
+            .add_with_option(Fixed(20., 10.), FlexOption::flex(1.))
+            .add(Fixed(10., 10.));
+        let (size, children) = layout(&mut flex, Size::new(f32::INFINITY, 50.));
+
+        assert_eq!(size.width, 30.);
+        assert_eq!(children[0], (Vector2::new(0., 0.), Size::new(20., 10.)));
+        assert_eq!(children[1], (Vector2::new(20., 0.), Size::new(10., 10.)));
+    }
+
+    #[test]
+    fn wrap_with_line_spacing() {
+        let mut flex = Flex::row()
+            .wrap(FlexWrap::Wrap)
+            .line_spacing(5.)
+            .add(Fixed(20., 10.))
+            .add(Fixed(20., 10.))
+            .add(Fixed(20., 10.));
+        let (size, children) = layout(&mut flex, Size::new(50., 50.));
+
+        assert_eq!(size.height, 25.);
+        assert_eq!(children[0].0, Vector2::new(0., 0.));
+        assert_eq!(children[1].0, Vector2::new(20., 0.));
+        assert_eq!(children[2].0, Vector2::new(0., 15.));
+    }
+
+    #[test]
+    fn space_between() {
+        let mut flex = Flex::row()
+            .main_axis_alignment(MainAxisAlignment::SpaceBetween)
+            .add(Fixed(10., 10.))
+            .add(Fixed(10., 10.))
+            .add(Fixed(10., 10.));
+        let (_, children) = layout(&mut flex, Size::new(100., 50.));
+
+        let xs: Vec<f32> = children.iter().map(|(origin, _)| origin.x).collect();
+        assert_eq!(xs, vec![0., 45., 90.]);
+    }
+
+    #[test]
+    fn space_evenly() {
+        let mut flex = Flex::row()
+            .main_axis_alignment(MainAxisAlignment::SpaceEvenly)
+            .add(Fixed(10., 10.))
+            .add(Fixed(10., 10.))
+            .add(Fixed(10., 10.));
+        let (_, children) = layout(&mut flex, Size::new(100., 50.));
+
+        let xs: Vec<f32> = children.iter().map(|(origin, _)| origin.x).collect();
+        assert_eq!(xs, vec![17.5, 45., 72.5]);
+    }
+
+    #[test]
+    fn without_flex_children() {
+        let mut flex = Flex::row()
+            .main_axis_alignment(MainAxisAlignment::SpaceAround)
+            .add(Fixed(10., 10.))
+            .add(Fixed(20., 10.));
+        let (size, children) = layout(&mut flex, Size::new(100., 50.));
+
+        assert!(size.width.is_finite() && size.height.is_finite());
+        assert_eq!(children[0].1, Size::new(10., 10.));
+        assert_eq!(children[1].1, Size::new(20., 10.));
+
+        let mut empty = Flex::row().main_axis_alignment(MainAxisAlignment::SpaceBetween);
+        let (size, children) = layout(&mut empty, Size::new(100., 50.));
+
+        assert!(size.width.is_finite() && size.height.is_finite());
+        assert!(children.is_empty());
+    }
+
+    #[test]
+    fn relayout_siblings_reading_changed_data() {
+        let collapse = CoreExt::<_, TestBackend>::controlled(
+            Fixed(10., 10.),
+            Click::new(|state: &mut VisibilityState| *state = VisibilityState::Collapsed),
+        );
+        let lens = Field::new(
+            |state: &VisibilityState| state,
+            |state: &mut VisibilityState| state,
+        );
+        let flex: Flex<VisibilityState, TestBackend> = Flex::column()
+            .add(collapse)
+            .add(Visibility::<_, _, TestBackend>::new(Fixed(10., 30.), lens))
+            .add(Fixed(10., 10.));
+        let mut interface = Interface::new(flex);
+        let backend = TestBackend::default();
+        let mut state = VisibilityState::Visible;
+
+        interface.resize(Size::new(100., 100.));
+        interface.layout(&backend, &state);
+        assert_eq!(interface.paint(&state).0[2].0, Vector2::new(0., 40.));
+
+        interface.add_event(MouseClickEvent {
+            pos: Vector2::new(5., 5.),
+            mouse_button: MouseButton::Left,
+        });
+        interface.event(&mut state);
+        interface.layout(&backend, &state);
+
+        // The collapsed sibling isn't on the path of the click, but reads the data it changed
+        let drawn = interface.paint(&state).0;
+        assert_eq!(drawn.len(), 2);
+        assert_eq!(drawn[1].0, Vector2::new(0., 10.));
+    }
+}
@@ -0,0 +1,400 @@
+use std::ops::Range;
+
+use utopia_core::{
+    contexts::ContextProvider,
+    math::{Size, Vector2},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+
+use crate::{LayoutUnits, ValueConstraint};
+
+/// A Widget that places its children in cells of explicit columns and rows.
+///
+/// Children can span several tracks. Cells outside of the declared tracks add `Auto` tracks.
+pub struct Grid<T, B: Backend> {
+    children: Vec<GridChild<T, B>>,
+    columns: Vec<Track>,
+    rows: Vec<Track>,
+    column_gap: f32,
+    row_gap: f32,
+}
+
+struct GridChild<T, B: Backend> {
+    widget: WidgetPod<T, B>,
+    columns: Range<usize>,
+    rows: Range<usize>,
+    size: Size,
+}
+
+/// The size of a column or a row
+#[derive(Debug, Clone, PartialEq)]
+pub enum Track {
+    /// Sized in pixels or in percent of the grid. `Unconstrained` behaves like `Auto`
+    Fixed(ValueConstraint),
+    /// Share of the space left by the other tracks. Behaves like `Auto` when the grid is unbounded
+    Fraction(f32),
+    /// Sized by the children in the track
+    Auto,
+}
+
+impl Track {
+    pub fn pixels(pixels: f32) -> Self {
+        Track::Fixed(ValueConstraint::pixels(pixels))
+    }
+
+    pub fn percent(percent: f32) -> Self {
+        Track::Fixed(ValueConstraint::percent(percent))
+    }
+
+    pub fn fraction(fraction: f32) -> Self {
+        Track::Fraction(fraction)
+    }
+
+    pub fn auto() -> Self {
+        Track::Auto
+    }
+
+    /// Whether the track is sized by its children
+    fn is_intrinsic(&self, available: f32, units: &LayoutUnits) -> bool {
+        match self {
+            Track::Fixed(value) => value.solve(available, units).is_none(),
+            Track::Fraction(_) => !available.is_finite(),
+            Track::Auto => true,
+        }
+    }
+}
+
+impl<T, B: Backend> Default for Grid<T, B> {
+    fn default() -> Self {
+        Grid {
+            children: Vec::new(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_gap: 0.,
+            row_gap: 0.,
+        }
+    }
+}
+
+impl<T, B: Backend> Grid<T, B> {
+    pub fn new() -> Self {
+        Grid::default()
+    }
+
+    pub fn column(mut self, track: Track) -> Self {
+        self.columns.push(track);
+        self
+    }
+
+    pub fn row(mut self, track: Track) -> Self {
+        self.rows.push(track);
+        self
+    }
+
+    pub fn column_gap(mut self, gap: f32) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    pub fn row_gap(mut self, gap: f32) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    pub fn gap(self, gap: f32) -> Self {
+        self.column_gap(gap).row_gap(gap)
+    }
+
+    /// Places `widget` in the cell at `column` and `row`
+    pub fn add<TW: TypedWidget<T, B> + 'static>(
+        self,
+        widget: TW,
+        column: usize,
+        row: usize,
+    ) -> Self {
+        self.add_span(widget, column, row, 1, 1)
+    }
+
+    /// Places `widget` over `column_span` columns and `row_span` rows, starting at `column` and `row`
+    pub fn add_span<TW: TypedWidget<T, B> + 'static>(
+        mut self,
+        widget: TW,
+        column: usize,
+        row: usize,
+        column_span: usize,
+        row_span: usize,
+    ) -> Self {
+        self.children.push(GridChild {
+            widget: WidgetPod::new(widget),
+            columns: column..column + column_span.max(1),
+            rows: row..row + row_span.max(1),
+            size: Size::default(),
+        });
+        self
+    }
+}
+
+/// Computes the size of every track.
+///
+/// `contributions` are the sizes of the children spanning at least one intrinsic track
+fn resolve_tracks(
+    tracks: &[Track],
+    available: f32,
+    gap: f32,
+    contributions: &[(Range<usize>, f32)],
+    units: &LayoutUnits,
+) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fixed(value) => value.solve(available, units).unwrap_or(0.).max(0.),
+            _ => 0.,
+        })
+        .collect();
+
+    // Step 1 : Intrinsic tracks fit the children spanning only them
+    for (span, size) in contributions.iter().filter(|(span, _)| span.len() == 1) {
+        if tracks[span.start].is_intrinsic(available, units) {
+            sizes[span.start] = sizes[span.start].max(*size);
+        }
+    }
+
+    // Step 2 : Intrinsic tracks grow evenly to fit the children spanning several tracks
+    for (span, size) in contributions.iter().filter(|(span, _)| span.len() > 1) {
+        let spanned = sizes[span.clone()].iter().sum::<f32>() + gap * (span.len() - 1) as f32;
+        let intrinsic: Vec<usize> = span
+            .clone()
+            .filter(|index| tracks[*index].is_intrinsic(available, units))
+            .collect();
+        if *size > spanned && !intrinsic.is_empty() {
+            let extra = (size - spanned) / intrinsic.len() as f32;
+            intrinsic
+                .into_iter()
+                .for_each(|index| sizes[index] += extra);
+        }
+    }
+
+    // Step 3 : Fraction tracks share the space left
+    if available.is_finite() {
+        let fraction_sum: f32 = tracks
+            .iter()
+            .filter_map(|track| match track {
+                Track::Fraction(fraction) => Some(*fraction),
+                _ => None,
+            })
+            .sum();
+        if fraction_sum > 0. {
+            let used: f32 = sizes.iter().sum::<f32>() + gap * tracks.len().saturating_sub(1) as f32;
+            let free_space = (available - used).max(0.);
+            for (track, size) in tracks.iter().zip(sizes.iter_mut()) {
+                if let Track::Fraction(fraction) = track {
+                    *size = free_space * fraction / fraction_sum;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the position of every track, and the total length
+fn positions(sizes: &[f32], gap: f32) -> (Vec<f32>, f32) {
+    let mut position = 0.;
+    let positions = sizes
+        .iter()
+        .map(|size| {
+            let start = position;
+            position += size + gap;
+            start
+        })
+        .collect();
+    (positions, (position - gap).max(0.))
+}
+
+fn span_length(sizes: &[f32], span: &Range<usize>, gap: f32) -> f32 {
+    sizes[span.clone()].iter().sum::<f32>() + gap * (span.len() - 1) as f32
+}
+
+impl<T, B: Backend + ContextProvider<LayoutUnits>> Grid<T, B> {
+    /// Declared columns and rows, with cells outside of them adding auto-sized tracks
+    fn tracks(&self) -> (Vec<Track>, Vec<Track>) {
+        let mut columns = self.columns.clone();
+        let mut rows = self.rows.clone();
+        let column_count = self.children.iter().map(|c| c.columns.end).max();
+        let row_count = self.children.iter().map(|c| c.rows.end).max();
+        columns.resize(columns.len().max(column_count.unwrap_or(0)), Track::Auto);
+        rows.resize(rows.len().max(row_count.unwrap_or(0)), Track::Auto);
+        (columns, rows)
+    }
+
+    /// Width of every column, with intrinsic columns fitting the intrinsic width of their children
+    fn column_sizes(
+        &mut self,
+        columns: &[Track],
+        available_width: f32,
+        min: bool,
+        context: &B,
+        data: &T,
+    ) -> Vec<f32> {
+        let units: &LayoutUnits = context.provide();
+        let contributions: Vec<(Range<usize>, f32)> = self
+            .children
+            .iter_mut()
+            .filter(|child| {
+                columns[child.columns.clone()]
+                    .iter()
+                    .any(|track| track.is_intrinsic(available_width, units))
+            })
+            .map(|child| {
+                let widget = &mut child.widget;
+                let width = match min {
+                    true => TypedWidget::<T, B>::min_intrinsic_width(
+                        widget,
+                        f32::INFINITY,
+                        context,
+                        data,
+                    ),
+                    false => TypedWidget::<T, B>::max_intrinsic_width(
+                        widget,
+                        f32::INFINITY,
+                        context,
+                        data,
+                    ),
+                };
+                (child.columns.clone(), width)
+            })
+            .collect();
+        resolve_tracks(
+            columns,
+            available_width,
+            self.column_gap,
+            &contributions,
+            units,
+        )
+    }
+
+    /// Height of every row, with intrinsic rows fitting the children at the width of their columns
+    fn row_sizes(
+        &mut self,
+        rows: &[Track],
+        column_sizes: &[f32],
+        available_height: f32,
+        min: bool,
+        context: &B,
+        data: &T,
+    ) -> Vec<f32> {
+        let units: &LayoutUnits = context.provide();
+        let column_gap = self.column_gap;
+        let contributions: Vec<(Range<usize>, f32)> = self
+            .children
+            .iter_mut()
+            .filter(|child| {
+                rows[child.rows.clone()]
+                    .iter()
+                    .any(|track| track.is_intrinsic(available_height, units))
+            })
+            .map(|child| {
+                let width = span_length(column_sizes, &child.columns, column_gap);
+                let widget = &mut child.widget;
+                let height = match min {
+                    true => TypedWidget::<T, B>::min_intrinsic_height(widget, width, context, data),
+                    false => {
+                        TypedWidget::<T, B>::max_intrinsic_height(widget, width, context, data)
+                    }
+                };
+                (child.rows.clone(), height)
+            })
+            .collect();
+        resolve_tracks(rows, available_height, self.row_gap, &contributions, units)
+    }
+}
+
+impl<T, B: Backend + ContextProvider<LayoutUnits>> Widget<T> for Grid<T, B>
+where
+    B::Event: Clone,
+{
+    type Primitive = CommonPrimitive<B::Primitive>;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        // Step 1 : Size columns
+        let (columns, rows) = self.tracks();
+        let column_sizes = self.column_sizes(&columns, bc.max.width, false, context, data);
+
+        // Step 2 : Size rows, knowing the width of every child
+        let row_sizes = self.row_sizes(&rows, &column_sizes, bc.max.height, false, context, data);
+
+        // Step 3 : Layout children in their cells
+        let (column_positions, width) = positions(&column_sizes, self.column_gap);
+        let (row_positions, height) = positions(&row_sizes, self.row_gap);
+        let (column_gap, row_gap) = (self.column_gap, self.row_gap);
+        for child in self.children.iter_mut() {
+            let cell = Size::new(
+                span_length(&column_sizes, &child.columns, column_gap),
+                span_length(&row_sizes, &child.rows, row_gap),
+            );
+            let constraint = BoxConstraints {
+                min: cell,
+                max: cell,
+            };
+            child.size = TypedWidget::<T, B>::layout(&mut child.widget, &constraint, context, data);
+            child.widget.set_origin(Vector2::new(
+                column_positions[child.columns.start],
+                row_positions[child.rows.start],
+            ));
+        }
+
+        bc.constrain(Size::new(width, height))
+    }
+
+    fn min_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &T) -> f32 {
+        let (columns, _) = self.tracks();
+        let sizes = self.column_sizes(&columns, f32::INFINITY, true, context, data);
+        positions(&sizes, self.column_gap).1
+    }
+
+    fn max_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &T) -> f32 {
+        let (columns, _) = self.tracks();
+        let sizes = self.column_sizes(&columns, f32::INFINITY, false, context, data);
+        positions(&sizes, self.column_gap).1
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let (columns, rows) = self.tracks();
+        let column_sizes = self.column_sizes(&columns, width, false, context, data);
+        let sizes = self.row_sizes(&rows, &column_sizes, f32::INFINITY, true, context, data);
+        positions(&sizes, self.row_gap).1
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let (columns, rows) = self.tracks();
+        let column_sizes = self.column_sizes(&columns, width, false, context, data);
+        let sizes = self.row_sizes(&rows, &column_sizes, f32::INFINITY, false, context, data);
+        positions(&sizes, self.row_gap).1
+    }
+
+    fn draw(&self, origin: Vector2, _size: Size, data: &T) -> Self::Primitive {
+        let children = self
+            .children
+            .iter()
+            .map(|child| TypedWidget::<T, B>::draw(&child.widget, origin, child.size, data))
+            .collect();
+
+        CommonPrimitive::Group { children }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        _size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        self.children.iter_mut().find_map(|child| {
+            TypedWidget::<T, B>::event(&mut child.widget, origin, child.size, data, event.clone())
+        })
+    }
+}
@@ -1,10 +1,11 @@
 use utopia_core::{
+    contexts::ContextProvider,
     math::{Size, Vector2},
     widgets::{pod::WidgetPod, TypedWidget, Widget},
     Backend, BoxConstraints,
 };
 
-use crate::SizeConstraint;
+use crate::{LayoutUnits, SizeConstraint, ValueConstraint};
 
 /// A Widget that forces its child to be at most as big as its constraint
 pub struct MaxSize<T, B: Backend> {
@@ -19,9 +20,17 @@ impl<T, B: Backend> MaxSize<T, B> {
             constraint,
         }
     }
+
+    /// Lowers an intrinsic length of the child to the constraint, when it can be solved without a parent
+    fn lower(&self, constraint: &ValueConstraint, length: f32, units: &LayoutUnits) -> f32 {
+        match constraint.solve(f32::INFINITY, units) {
+            Some(max) => length.min(max),
+            None => length,
+        }
+    }
 }
 
-impl<T, B: Backend> Widget<T> for MaxSize<T, B> {
+impl<T, B: Backend + ContextProvider<LayoutUnits>> Widget<T> for MaxSize<T, B> {
     type Primitive = B::Primitive;
     type Context = B;
     type Event = B::Event;
@@ -32,15 +41,16 @@ impl<T, B: Backend> Widget<T> for MaxSize<T, B> {
     }
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        let units: &LayoutUnits = context.provide();
         let width = self
             .constraint
             .width
-            .solve(bc.max.width)
+            .solve(bc.max.width, units)
             .unwrap_or(bc.max.width);
         let height = self
             .constraint
             .height
-            .solve(bc.max.height)
+            .solve(bc.max.height, units)
             .unwrap_or(bc.max.height);
 
         let child_bc = BoxConstraints {
@@ -53,6 +63,30 @@ impl<T, B: Backend> Widget<T> for MaxSize<T, B> {
         TypedWidget::<T, B>::layout(&mut self.widget, &child_bc, context, data)
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let width =
+            TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data);
+        self.lower(&self.constraint.width, width, context.provide())
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let width =
+            TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data);
+        self.lower(&self.constraint.width, width, context.provide())
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let height =
+            TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data);
+        self.lower(&self.constraint.height, height, context.provide())
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let height =
+            TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data);
+        self.lower(&self.constraint.height, height, context.provide())
+    }
+
     fn event(
         &mut self,
         origin: Vector2,
@@ -1,22 +1,32 @@
 use align::Align;
 use padding::Padding;
-use utopia_core::{widgets::TypedWidget, Backend};
+use utopia_core::{lens::Lens, widgets::TypedWidget, Backend};
 
 use crate::SizeConstraint;
 
 use self::{
     align::{HorizontalAlignment, VerticalAlignment},
+    aspect_ratio::AspectRatio,
     max_size::MaxSize,
     min_size::MinSize,
+    sized_box::SizedBox,
+    visibility::{Visibility, VisibilityState},
 };
 
 pub mod align;
+pub mod aspect_ratio;
+pub mod constraint_layout;
 pub mod flex;
+pub mod grid;
 pub mod max_size;
 pub mod min_size;
 pub mod padding;
+pub mod responsive;
+pub mod sized_box;
 pub mod spacer;
+pub mod split;
 pub mod stack;
+pub mod visibility;
 
 pub trait LayoutExt<T, B: Backend>: TypedWidget<T, B> + Sized + 'static {
     fn padding(self) -> Padding<T, B> {
@@ -40,6 +50,18 @@ pub trait LayoutExt<T, B: Backend>: TypedWidget<T, B> + Sized + 'static {
     fn max_size(self, constraint: SizeConstraint) -> MaxSize<T, B> {
         MaxSize::new(self, constraint)
     }
+
+    fn sized(self, constraint: SizeConstraint) -> SizedBox<T, B> {
+        SizedBox::new(self, constraint)
+    }
+
+    fn aspect_ratio(self, ratio: f32) -> AspectRatio<T, B> {
+        AspectRatio::new(self, ratio)
+    }
+
+    fn visibility<L: Lens<T, VisibilityState>>(self, lens: L) -> Visibility<T, L, B> {
+        Visibility::new(self, lens)
+    }
 }
 
 impl<T, B: Backend, W: TypedWidget<T, B> + Sized + 'static> LayoutExt<T, B> for W {}
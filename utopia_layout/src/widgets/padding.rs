@@ -1,79 +1,175 @@
 use utopia_core::{
-    math::{Size, Vector2},
+    contexts::ContextProvider,
+    math::{Insets, LayoutDirection, Size, Vector2},
     widgets::{pod::WidgetPod, TypedWidget, Widget},
     Backend, BoxConstraints,
 };
 
+use crate::{LayoutUnits, ValueConstraint};
+
+/// A Widget that adds space around its child.
+///
+/// Percentages are resolved against the width of the parent for the left and right sides,
+/// and against its height for the top and bottom sides.
+/// The left and right sides are leading and trailing, and swap in right-to-left layouts.
 pub struct Padding<T, B: Backend> {
     widget: WidgetPod<T, B>,
-    padding_left: u32,
-    padding_top: u32,
-    padding_right: u32,
-    padding_bottom: u32,
+    leading: ValueConstraint,
+    top: ValueConstraint,
+    trailing: ValueConstraint,
+    bottom: ValueConstraint,
 }
 
 impl<T, B: Backend> Padding<T, B> {
     pub fn new<TW: TypedWidget<T, B> + 'static>(widget: TW) -> Self {
         Padding {
             widget: WidgetPod::new(widget),
-            padding_left: 0,
-            padding_right: 0,
-            padding_top: 0,
-            padding_bottom: 0,
+            leading: ValueConstraint::pixels(0.),
+            top: ValueConstraint::pixels(0.),
+            trailing: ValueConstraint::pixels(0.),
+            bottom: ValueConstraint::pixels(0.),
         }
     }
 
-    pub fn top(mut self, padding_top: u32) -> Self {
-        self.padding_top = padding_top;
+    pub fn top<V: Into<ValueConstraint>>(mut self, top: V) -> Self {
+        self.top = top.into();
         self
     }
 
-    pub fn bottom(mut self, padding_bottom: u32) -> Self {
-        self.padding_bottom = padding_bottom;
+    pub fn bottom<V: Into<ValueConstraint>>(mut self, bottom: V) -> Self {
+        self.bottom = bottom.into();
         self
     }
 
-    pub fn left(mut self, padding_left: u32) -> Self {
-        self.padding_left = padding_left;
+    /// Sets the leading side, on the right in right-to-left layouts
+    pub fn left<V: Into<ValueConstraint>>(mut self, left: V) -> Self {
+        self.leading = left.into();
         self
     }
 
-    pub fn right(mut self, padding_right: u32) -> Self {
-        self.padding_right = padding_right;
+    /// Sets the trailing side, on the left in right-to-left layouts
+    pub fn right<V: Into<ValueConstraint>>(mut self, right: V) -> Self {
+        self.trailing = right.into();
         self
     }
 
-    pub fn all(mut self, padding: u32) -> Self {
-        self.padding_bottom = padding;
-        self.padding_left = padding;
-        self.padding_right = padding;
-        self.padding_top = padding;
-        self
+    /// Sets the left and right sides
+    pub fn horizontal<V: Into<ValueConstraint>>(self, horizontal: V) -> Self {
+        let horizontal = horizontal.into();
+        self.left(horizontal.clone()).right(horizontal)
+    }
+
+    /// Sets the top and bottom sides
+    pub fn vertical<V: Into<ValueConstraint>>(self, vertical: V) -> Self {
+        let vertical = vertical.into();
+        self.top(vertical.clone()).bottom(vertical)
+    }
+
+    pub fn symmetric<H: Into<ValueConstraint>, V: Into<ValueConstraint>>(
+        self,
+        horizontal: H,
+        vertical: V,
+    ) -> Self {
+        self.horizontal(horizontal).vertical(vertical)
+    }
+
+    pub fn all<V: Into<ValueConstraint>>(self, padding: V) -> Self {
+        let padding = padding.into();
+        self.horizontal(padding.clone()).vertical(padding)
+    }
+
+    /// Sets every side in pixels
+    pub fn insets(self, insets: Insets) -> Self {
+        self.left(insets.left)
+            .top(insets.top)
+            .right(insets.right)
+            .bottom(insets.bottom)
+    }
+
+    /// Resolves every side against the size of the parent
+    fn resolve(
+        &self,
+        parent_size: Size,
+        units: &LayoutUnits,
+        direction: &LayoutDirection,
+    ) -> Insets {
+        let solve = |value: &ValueConstraint, parent_size: f32| {
+            value.solve(parent_size, units).unwrap_or(0.).max(0.)
+        };
+        let leading = solve(&self.leading, parent_size.width);
+        let trailing = solve(&self.trailing, parent_size.width);
+        let (left, right) = match direction {
+            LayoutDirection::LeftToRight => (leading, trailing),
+            LayoutDirection::RightToLeft => (trailing, leading),
+        };
+        Insets {
+            left,
+            top: solve(&self.top, parent_size.height),
+            right,
+            bottom: solve(&self.bottom, parent_size.height),
+        }
     }
 }
 
-impl<T, B: Backend> Widget<T> for Padding<T, B> {
+/// Percentages can't be resolved when computing intrinsic sizes
+const UNKNOWN_PARENT: Size = Size {
+    width: f32::INFINITY,
+    height: f32::INFINITY,
+};
+
+impl<T, B> Widget<T> for Padding<T, B>
+where
+    B: Backend + ContextProvider<LayoutUnits> + ContextProvider<LayoutDirection>,
+{
     type Primitive = B::Primitive;
     type Context = B;
     type Event = B::Event;
     type Reaction = B::EventReaction;
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
-        let width = (self.padding_left + self.padding_right) as f32;
-        let height = (self.padding_top + self.padding_bottom) as f32;
-        let size = Size { width, height };
-        let child_bc = bc.shrink(size);
-        self.widget.set_origin(Vector2 {
-            x: self.padding_left as f32,
-            y: self.padding_top as f32,
-        });
+        let insets = self.resolve(bc.max, context.provide(), context.provide());
+        let child_bc = bc.shrink(insets);
+        self.widget.set_origin(insets.origin());
         let child_size = TypedWidget::<T, B>::layout(&mut self.widget, &child_bc, context, data);
         Size {
-            width: width + child_size.width,
-            height: height + child_size.height,
+            width: insets.width() + child_size.width,
+            height: insets.height() + child_size.height,
         }
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = self.resolve(UNKNOWN_PARENT, context.provide(), context.provide());
+        let height = (height - insets.height()).max(0.);
+        insets.width()
+            + TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = self.resolve(UNKNOWN_PARENT, context.provide(), context.provide());
+        let height = (height - insets.height()).max(0.);
+        insets.width()
+            + TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = self.resolve(UNKNOWN_PARENT, context.provide(), context.provide());
+        let width = (width - insets.width()).max(0.);
+        insets.height()
+            + TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let insets = self.resolve(UNKNOWN_PARENT, context.provide(), context.provide());
+        let width = (width - insets.width()).max(0.);
+        insets.height()
+            + TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        TypedWidget::<T, B>::baseline(&self.widget, context, data)
+            .map(|baseline| baseline + self.widget.origin().y)
+    }
+
     fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
         TypedWidget::<T, B>::draw(&self.widget, origin, size, data)
     }
@@ -0,0 +1,143 @@
+use utopia_core::{
+    math::{Size, Vector2},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+
+type Builder<T, B> = Box<dyn Fn() -> WidgetPod<T, B>>;
+
+/// A Widget that picks one of its branches depending on the space available.
+///
+/// Branches are built the first time they are picked, and keep their state when another
+/// branch is picked, e.g. after the window is resized.
+pub struct Responsive<T, B: Backend> {
+    branches: Vec<Branch<T, B>>,
+    active: usize,
+}
+
+struct Branch<T, B: Backend> {
+    breakpoint: Breakpoint,
+    builder: Builder<T, B>,
+    widget: Option<WidgetPod<T, B>>,
+}
+
+/// The minimum space for a branch of a `Responsive` to be picked
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Breakpoint {
+    pub min_width: f32,
+    pub min_height: f32,
+}
+
+impl Breakpoint {
+    pub fn new() -> Self {
+        Breakpoint::default()
+    }
+
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn min_height(mut self, min_height: f32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    fn matches(&self, max: Size) -> bool {
+        max.width >= self.min_width && max.height >= self.min_height
+    }
+}
+
+impl<T, B: Backend> Responsive<T, B> {
+    /// A Responsive using `builder` when no breakpoint matches
+    pub fn new<TW, F>(builder: F) -> Self
+    where
+        TW: TypedWidget<T, B> + 'static,
+        F: Fn() -> TW + 'static,
+    {
+        Responsive {
+            branches: Vec::new(),
+            active: 0,
+        }
+        .breakpoint(Breakpoint::default(), builder)
+    }
+
+    /// Uses `builder` when the space available matches `breakpoint`.
+    ///
+    /// The last matching breakpoint wins, so breakpoints should be added from the smallest to the largest
+    pub fn breakpoint<TW, F>(mut self, breakpoint: Breakpoint, builder: F) -> Self
+    where
+        TW: TypedWidget<T, B> + 'static,
+        F: Fn() -> TW + 'static,
+    {
+        self.branches.push(Branch {
+            breakpoint,
+            builder: Box::new(move || WidgetPod::new(builder())),
+            widget: None,
+        });
+        self
+    }
+
+    /// Index of the branch picked by the last layout, `0` being the one given to `new`
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Widget of the active branch, built if needed
+    fn active_widget(&mut self) -> &mut WidgetPod<T, B> {
+        let branch = &mut self.branches[self.active];
+        let builder = &branch.builder;
+        branch.widget.get_or_insert_with(builder)
+    }
+}
+
+impl<T, B: Backend> Widget<T> for Responsive<T, B> {
+    type Primitive = B::Primitive;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        self.active = self
+            .branches
+            .iter()
+            .rposition(|branch| branch.breakpoint.matches(bc.max))
+            .unwrap_or(0);
+
+        TypedWidget::<T, B>::layout(self.active_widget(), bc, context, data)
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(self.active_widget(), height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(self.active_widget(), height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(self.active_widget(), width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(self.active_widget(), width, context, data)
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        match &self.branches[self.active].widget {
+            Some(widget) => TypedWidget::<T, B>::draw(widget, origin, size, data),
+            None => CommonPrimitive::None.into(),
+        }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        let widget = self.branches[self.active].widget.as_mut()?;
+        TypedWidget::<T, B>::event(widget, origin, size, data, event)
+    }
+}
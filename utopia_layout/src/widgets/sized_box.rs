@@ -0,0 +1,108 @@
+use utopia_core::{
+    contexts::ContextProvider,
+    math::{Size, Vector2},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints,
+};
+
+use crate::{LayoutUnits, SizeConstraint, ValueConstraint};
+
+/// A Widget that forces its child to an exact size.
+///
+/// An `Unconstrained` dimension is left to the child
+pub struct SizedBox<T, B: Backend> {
+    widget: WidgetPod<T, B>,
+    constraint: SizeConstraint,
+}
+
+impl<T, B: Backend> SizedBox<T, B> {
+    pub fn new<W: TypedWidget<T, B> + 'static>(widget: W, constraint: SizeConstraint) -> Self {
+        SizedBox {
+            widget: WidgetPod::new(widget),
+            constraint,
+        }
+    }
+
+    /// Length set by the constraint, when it can be solved without knowing the parent
+    fn fixed(constraint: &ValueConstraint, units: &LayoutUnits) -> Option<f32> {
+        constraint.solve(f32::INFINITY, units)
+    }
+}
+
+impl<T, B: Backend + ContextProvider<LayoutUnits>> Widget<T> for SizedBox<T, B> {
+    type Primitive = B::Primitive;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        TypedWidget::<T, B>::draw(&self.widget, origin, size, data)
+    }
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        let units: &LayoutUnits = context.provide();
+        let mut child_bc = BoxConstraints {
+            min: bc.min,
+            max: bc.max,
+        };
+        if let Some(width) = self.constraint.width.solve(bc.max.width, units) {
+            let width = width.max(bc.min.width).min(bc.max.width);
+            child_bc.min.width = width;
+            child_bc.max.width = width;
+        }
+        if let Some(height) = self.constraint.height.solve(bc.max.height, units) {
+            let height = height.max(bc.min.height).min(bc.max.height);
+            child_bc.min.height = height;
+            child_bc.max.height = height;
+        }
+
+        let child_size = TypedWidget::<T, B>::layout(&mut self.widget, &child_bc, context, data);
+        child_bc.constrain(child_size)
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let units: &LayoutUnits = context.provide();
+        if let Some(width) = Self::fixed(&self.constraint.width, units) {
+            return width;
+        }
+        let height = Self::fixed(&self.constraint.height, units).unwrap_or(height);
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let units: &LayoutUnits = context.provide();
+        if let Some(width) = Self::fixed(&self.constraint.width, units) {
+            return width;
+        }
+        let height = Self::fixed(&self.constraint.height, units).unwrap_or(height);
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let units: &LayoutUnits = context.provide();
+        if let Some(height) = Self::fixed(&self.constraint.height, units) {
+            return height;
+        }
+        let width = Self::fixed(&self.constraint.width, units).unwrap_or(width);
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let units: &LayoutUnits = context.provide();
+        if let Some(height) = Self::fixed(&self.constraint.height, units) {
+            return height;
+        }
+        let width = Self::fixed(&self.constraint.width, units).unwrap_or(width);
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        TypedWidget::<T, B>::event(&mut self.widget, origin, size, data, event)
+    }
+}
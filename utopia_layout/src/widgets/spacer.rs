@@ -10,6 +10,7 @@ impl Spacer {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Axis {
     Vertical,
     Horizontal,
@@ -46,4 +47,21 @@ impl<T> Widget<T> for Spacer {
             },
         }
     }
+
+    // Spacers only take the space left by their siblings
+    fn min_intrinsic_width(&mut self, _height: f32, _context: &Self::Context, _data: &T) -> f32 {
+        0.
+    }
+
+    fn max_intrinsic_width(&mut self, _height: f32, _context: &Self::Context, _data: &T) -> f32 {
+        0.
+    }
+
+    fn min_intrinsic_height(&mut self, _width: f32, _context: &Self::Context, _data: &T) -> f32 {
+        0.
+    }
+
+    fn max_intrinsic_height(&mut self, _width: f32, _context: &Self::Context, _data: &T) -> f32 {
+        0.
+    }
 }
@@ -0,0 +1,281 @@
+use utopia_core::{
+    controllers::{drag::DragEvent, TransformEvent},
+    lens::Lens,
+    math::{Size, Vector2},
+    reactions::CommonReaction,
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+use utopia_decorations::primitives::quad::QuadPrimitive;
+
+use super::flex::FlexDirection;
+
+/// A Widget showing two panes separated by a divider that can be dragged.
+///
+/// The ratio of the space given to the first pane is read from and written to the data through a lens.
+pub struct Split<T, L, Color, B: Backend> {
+    first: WidgetPod<T, B>,
+    second: WidgetPod<T, B>,
+    ratio: L,
+    direction: FlexDirection,
+    min_sizes: (f32, f32),
+    divider_width: f32,
+    divider_color: Color,
+    /// Main size of the first pane at the last layout
+    first_size: f32,
+    /// Main size of both panes at the last layout
+    available: f32,
+    dragging: bool,
+}
+
+impl<T, L: Lens<T, f32>, Color: Default, B: Backend> Split<T, L, Color, B> {
+    fn new<TW1, TW2>(first: TW1, second: TW2, ratio: L, direction: FlexDirection) -> Self
+    where
+        TW1: TypedWidget<T, B> + 'static,
+        TW2: TypedWidget<T, B> + 'static,
+    {
+        Split {
+            first: WidgetPod::new(first),
+            second: WidgetPod::new(second),
+            ratio,
+            direction,
+            min_sizes: (0., 0.),
+            divider_width: 4.,
+            divider_color: Color::default(),
+            first_size: 0.,
+            available: 0.,
+            dragging: false,
+        }
+    }
+
+    /// Panes side by side, `first` on the left
+    pub fn horizontal<TW1, TW2>(first: TW1, second: TW2, ratio: L) -> Self
+    where
+        TW1: TypedWidget<T, B> + 'static,
+        TW2: TypedWidget<T, B> + 'static,
+    {
+        Split::new(first, second, ratio, FlexDirection::Row)
+    }
+
+    /// Panes on top of each other, `first` on the top
+    pub fn vertical<TW1, TW2>(first: TW1, second: TW2, ratio: L) -> Self
+    where
+        TW1: TypedWidget<T, B> + 'static,
+        TW2: TypedWidget<T, B> + 'static,
+    {
+        Split::new(first, second, ratio, FlexDirection::Column)
+    }
+}
+
+impl<T, L: Lens<T, f32>, Color, B: Backend> Split<T, L, Color, B> {
+    /// Minimum main size of each pane, the first one taking precedence when both can't fit
+    pub fn min_sizes(mut self, first: f32, second: f32) -> Self {
+        self.min_sizes = (first.max(0.), second.max(0.));
+        self
+    }
+
+    pub fn divider_width(mut self, divider_width: f32) -> Self {
+        self.divider_width = divider_width.max(0.);
+        self
+    }
+
+    pub fn divider_color(mut self, color: Color) -> Self {
+        self.divider_color = color;
+        self
+    }
+
+    /// Main size of the first pane for a given ratio, respecting the minimum sizes
+    fn first_size(&self, ratio: f32, available: f32) -> f32 {
+        let (min_first, min_second) = self.min_sizes;
+        (available * ratio)
+            .min(available - min_second)
+            .max(min_first)
+            .min(available)
+            .max(0.)
+    }
+
+    /// Intrinsic length of the split along the main axis if `along_main`, or across it otherwise.
+    ///
+    /// `other` is the length on the other axis, shared between panes like in a layout when it is the main one
+    fn intrinsic<F>(&mut self, along_main: bool, other: f32, data: &T, mut length: F) -> f32
+    where
+        F: FnMut(&mut WidgetPod<T, B>, f32) -> f32,
+    {
+        if along_main {
+            let first = length(&mut self.first, other).max(self.min_sizes.0);
+            let second = length(&mut self.second, other).max(self.min_sizes.1);
+            return first + self.divider_width + second;
+        }
+
+        let (first, second) = match other.is_finite() {
+            true => {
+                let available = (other - self.divider_width).max(0.);
+                let ratio = self.ratio.with(data, |ratio| *ratio);
+                let first = self.first_size(ratio, available);
+                (first, available - first)
+            }
+            false => (f32::INFINITY, f32::INFINITY),
+        };
+        length(&mut self.first, first).max(length(&mut self.second, second))
+    }
+}
+
+impl<T, L: Lens<T, f32>, Color: Clone, B: Backend> Widget<T> for Split<T, L, Color, B>
+where
+    B::Primitive: From<QuadPrimitive<Color>>,
+    B::Event: Clone + TransformEvent<DragEvent>,
+    B::EventReaction: From<CommonReaction>,
+{
+    type Primitive = CommonPrimitive<B::Primitive>;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        let direction = self.direction;
+        let cross_max = direction.cross(bc.max);
+        let cross_min = match cross_max.is_finite() {
+            true => cross_max,
+            false => direction.cross(bc.min),
+        };
+
+        // Step 1 : Split the main axis between both panes
+        let main = direction.main(bc.max);
+        let pane_bc = |main_min: f32, main_max: f32| BoxConstraints {
+            min: direction.size(main_min, cross_min),
+            max: direction.size(main_max, cross_max),
+        };
+        let (first_size, second_size) = if main.is_finite() {
+            self.available = (main - self.divider_width).max(0.);
+            let ratio = self.ratio.with(data, |ratio| *ratio);
+            let first = self.first_size(ratio, self.available);
+            (first, self.available - first)
+        } else {
+            // Without a bound, panes take their own size
+            let main_intrinsic = |pane: &mut WidgetPod<T, B>| match direction {
+                FlexDirection::Row => {
+                    TypedWidget::<T, B>::max_intrinsic_width(pane, cross_max, context, data)
+                }
+                FlexDirection::Column => {
+                    TypedWidget::<T, B>::max_intrinsic_height(pane, cross_max, context, data)
+                }
+            };
+            let first = main_intrinsic(&mut self.first);
+            let second = main_intrinsic(&mut self.second);
+            self.available = first + second;
+            (first, second)
+        };
+        self.first_size = first_size;
+
+        // Step 2 : Layout panes in their part
+        let first = TypedWidget::<T, B>::layout(
+            &mut self.first,
+            &pane_bc(first_size, first_size),
+            context,
+            data,
+        );
+        let second = TypedWidget::<T, B>::layout(
+            &mut self.second,
+            &pane_bc(second_size, second_size),
+            context,
+            data,
+        );
+        self.first.set_origin(Vector2::ZERO);
+        self.second
+            .set_origin(direction.vector(first_size + self.divider_width, 0.));
+
+        let cross = direction.cross(first).max(direction.cross(second));
+        bc.constrain(direction.size(first_size + self.divider_width + second_size, cross))
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let along_main = matches!(self.direction, FlexDirection::Row);
+        self.intrinsic(along_main, height, data, |pane, height| {
+            TypedWidget::<T, B>::min_intrinsic_width(pane, height, context, data)
+        })
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        let along_main = matches!(self.direction, FlexDirection::Row);
+        self.intrinsic(along_main, height, data, |pane, height| {
+            TypedWidget::<T, B>::max_intrinsic_width(pane, height, context, data)
+        })
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let along_main = matches!(self.direction, FlexDirection::Column);
+        self.intrinsic(along_main, width, data, |pane, width| {
+            TypedWidget::<T, B>::min_intrinsic_height(pane, width, context, data)
+        })
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        let along_main = matches!(self.direction, FlexDirection::Column);
+        self.intrinsic(along_main, width, data, |pane, width| {
+            TypedWidget::<T, B>::max_intrinsic_height(pane, width, context, data)
+        })
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        let direction = self.direction;
+        let divider = QuadPrimitive {
+            color: self.divider_color.clone(),
+            border_radius: 0,
+            origin: origin + direction.vector(self.first_size, 0.),
+            size: direction.size(self.divider_width, direction.cross(size)),
+        };
+        let children = vec![
+            TypedWidget::<T, B>::draw(&self.first, origin, size, data),
+            divider.into(),
+            TypedWidget::<T, B>::draw(&self.second, origin, size, data),
+        ];
+
+        CommonPrimitive::Group { children }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        if let Some(drag) = TransformEvent::<DragEvent>::transform_event(event.clone()) {
+            let direction = self.direction;
+            let pos = drag.pos() - origin;
+            let main = match direction {
+                FlexDirection::Row => pos.x,
+                FlexDirection::Column => pos.y,
+            };
+            match drag {
+                DragEvent::Pressed(_) => {
+                    let on_divider = main >= self.first_size
+                        && main <= self.first_size + self.divider_width
+                        && size.contains(pos);
+                    if on_divider {
+                        self.dragging = true;
+                        return None;
+                    }
+                }
+                DragEvent::Moved(_) if self.dragging => {
+                    if self.available <= 0. {
+                        return None;
+                    }
+                    let first = main - self.divider_width / 2.;
+                    let first = self.first_size(first / self.available, self.available);
+                    let ratio = first / self.available;
+                    self.ratio.with_mut(data, |r| *r = ratio);
+                    return Some(CommonReaction::ReLayout.into());
+                }
+                DragEvent::Released(_) if self.dragging => {
+                    self.dragging = false;
+                    return None;
+                }
+                _ => (),
+            }
+        }
+
+        TypedWidget::<T, B>::event(&mut self.first, origin, size, data, event.clone())
+            .or_else(|| TypedWidget::<T, B>::event(&mut self.second, origin, size, data, event))
+    }
+}
@@ -1,28 +1,148 @@
 use utopia_core::{
-    math::{Size, Vector2},
+    contexts::ContextProvider,
+    math::{LayoutDirection, Size, Vector2},
     widgets::{pod::WidgetPod, TypedWidget, Widget},
     Backend, BoxConstraints, CommonPrimitive,
 };
 
+use crate::{LayoutUnits, ValueConstraint};
+
+use super::align::{HorizontalAlignment, VerticalAlignment};
+
+/// A Widget that layers its children on top of each other.
+///
+/// The size of the stack is given by its non-positioned children, which are aligned within it.
+/// Positioned children are then placed relative to the edges of the stack.
 pub struct Stack<T, B: Backend> {
-    children: Vec<WidgetPod<T, B>>,
+    children: Vec<StackChild<T, B>>,
+    horizontal: HorizontalAlignment,
+    vertical: VerticalAlignment,
+}
+
+struct StackChild<T, B: Backend> {
+    widget: WidgetPod<T, B>,
+    positioned: Option<Positioned>,
+}
+
+/// Offsets of a child from the edges of its `Stack`.
+///
+/// `Unconstrained` offsets are unset. A child with both `left` and `right` set
+/// (resp. `top` and `bottom`) is stretched between them.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Positioned {
+    pub left: ValueConstraint,
+    pub top: ValueConstraint,
+    pub right: ValueConstraint,
+    pub bottom: ValueConstraint,
+}
+
+impl Positioned {
+    pub fn new() -> Self {
+        Positioned::default()
+    }
+
+    /// Stretches the child over the whole stack
+    pub fn fill() -> Self {
+        Positioned {
+            left: ValueConstraint::pixels(0.),
+            top: ValueConstraint::pixels(0.),
+            right: ValueConstraint::pixels(0.),
+            bottom: ValueConstraint::pixels(0.),
+        }
+    }
+
+    pub fn left(mut self, left: ValueConstraint) -> Self {
+        self.left = left;
+        self
+    }
+
+    pub fn top(mut self, top: ValueConstraint) -> Self {
+        self.top = top;
+        self
+    }
+
+    pub fn right(mut self, right: ValueConstraint) -> Self {
+        self.right = right;
+        self
+    }
+
+    pub fn bottom(mut self, bottom: ValueConstraint) -> Self {
+        self.bottom = bottom;
+        self
+    }
+}
+
+/// Returns the `(min, max)` length of a positioned child along one axis
+fn axis_constraints(start: Option<f32>, end: Option<f32>, space: f32) -> (f32, f32) {
+    match (start, end) {
+        (Some(start), Some(end)) => {
+            let length = (space - start - end).max(0.);
+            (length, length)
+        }
+        (Some(offset), None) | (None, Some(offset)) => (0., (space - offset).max(0.)),
+        (None, None) => (0., space),
+    }
+}
+
+/// Returns the position of a positioned child along one axis, if it sets an offset
+fn axis_position(start: Option<f32>, end: Option<f32>, space: f32, length: f32) -> Option<f32> {
+    start.or_else(|| end.map(|end| space - end - length))
 }
 
 impl<T, B: Backend> Stack<T, B> {
     pub fn new() -> Self {
         Stack {
             children: Vec::new(),
+            horizontal: HorizontalAlignment::Left,
+            vertical: VerticalAlignment::Top,
         }
     }
 
     pub fn add<TW: TypedWidget<T, B> + 'static>(mut self, child: TW) -> Self {
-        self.children.push(WidgetPod::new(child));
+        self.children.push(StackChild {
+            widget: WidgetPod::new(child),
+            positioned: None,
+        });
+        self
+    }
+
+    pub fn add_positioned<TW: TypedWidget<T, B> + 'static>(
+        mut self,
+        child: TW,
+        positioned: Positioned,
+    ) -> Self {
+        self.children.push(StackChild {
+            widget: WidgetPod::new(child),
+            positioned: Some(positioned),
+        });
         self
     }
+
+    /// Horizontal alignment of the children that don't set a horizontal offset
+    pub fn horizontal(mut self, horizontal_alignment: HorizontalAlignment) -> Self {
+        self.horizontal = horizontal_alignment;
+        self
+    }
+
+    /// Vertical alignment of the children that don't set a vertical offset
+    pub fn vertical(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.vertical = vertical_alignment;
+        self
+    }
+
+    /// Largest intrinsic length of the non-positioned children, which give the size of the stack
+    fn intrinsic(&mut self, mut length: impl FnMut(&mut WidgetPod<T, B>) -> f32) -> f32 {
+        self.children
+            .iter_mut()
+            .filter(|c| c.positioned.is_none())
+            .map(|c| length(&mut c.widget))
+            .fold(0., f32::max)
+    }
 }
 
-impl<T, B: Backend> Widget<T> for Stack<T, B>
+impl<T, B> Widget<T> for Stack<T, B>
 where
+    B: Backend + ContextProvider<LayoutUnits> + ContextProvider<LayoutDirection>,
     B::Event: Clone,
 {
     type Primitive = CommonPrimitive<B::Primitive>;
@@ -34,21 +154,104 @@ where
         let children: Vec<B::Primitive> = self
             .children
             .iter()
-            .map(|c| TypedWidget::<T, B>::draw(c, origin, size, data))
+            .map(|c| TypedWidget::<T, B>::draw(&c.widget, origin, size, data))
             .collect();
 
         CommonPrimitive::Group { children }
     }
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
-        self.children
+        // Step 1 : Non-positioned children give the size of the stack
+        let loosened = bc.loosen();
+        let sizes: Vec<Option<Size>> = self
+            .children
             .iter_mut()
-            .fold(Size::default(), |mut size, c| {
-                let child_size = TypedWidget::<T, B>::layout(c, bc, context, data);
-                size.width = size.width.max(child_size.width);
-                size.height = size.height.max(child_size.height);
-                size
+            .map(|c| match c.positioned {
+                Some(_) => None,
+                None => Some(TypedWidget::<T, B>::layout(
+                    &mut c.widget,
+                    &loosened,
+                    context,
+                    data,
+                )),
             })
+            .collect();
+        let size = match sizes.iter().any(Option::is_some) {
+            true => sizes.iter().flatten().fold(Size::ZERO, |size, child_size| {
+                Size::new(
+                    size.width.max(child_size.width),
+                    size.height.max(child_size.height),
+                )
+            }),
+            // Without non-positioned children, the stack fills its parent
+            false if bc.is_width_bounded() && bc.is_height_bounded() => bc.max,
+            false => bc.min,
+        };
+        let size = bc.constrain(size);
+
+        // Step 2 : Position children
+        let units: &LayoutUnits = context.provide();
+        let direction: &LayoutDirection = context.provide();
+        let direction = *direction;
+        let (horizontal, vertical) = (self.horizontal, self.vertical);
+        for (c, child_size) in self.children.iter_mut().zip(sizes) {
+            let positioned = match (&c.positioned, child_size) {
+                (None, Some(child_size)) => {
+                    c.widget.set_origin(Vector2::new(
+                        horizontal.position(size.width, child_size.width, direction),
+                        vertical.position(size.height, child_size.height),
+                    ));
+                    continue;
+                }
+                (Some(positioned), _) => positioned,
+                (None, None) => continue,
+            };
+
+            let left = positioned.left.solve(size.width, units);
+            let right = positioned.right.solve(size.width, units);
+            let top = positioned.top.solve(size.height, units);
+            let bottom = positioned.bottom.solve(size.height, units);
+            let (min_width, max_width) = axis_constraints(left, right, size.width);
+            let (min_height, max_height) = axis_constraints(top, bottom, size.height);
+            let child_bc = BoxConstraints {
+                min: Size::new(min_width, min_height),
+                max: Size::new(max_width, max_height),
+            };
+            let child_size = TypedWidget::<T, B>::layout(&mut c.widget, &child_bc, context, data);
+            c.widget.set_origin(Vector2::new(
+                axis_position(left, right, size.width, child_size.width).unwrap_or_else(|| {
+                    horizontal.position(size.width, child_size.width, direction)
+                }),
+                axis_position(top, bottom, size.height, child_size.height)
+                    .unwrap_or_else(|| vertical.position(size.height, child_size.height)),
+            ));
+        }
+
+        size
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(|widget| {
+            TypedWidget::<T, B>::min_intrinsic_width(widget, height, context, data)
+        })
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(|widget| {
+            TypedWidget::<T, B>::max_intrinsic_width(widget, height, context, data)
+        })
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(|widget| {
+            TypedWidget::<T, B>::min_intrinsic_height(widget, width, context, data)
+        })
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic(|widget| {
+            TypedWidget::<T, B>::max_intrinsic_height(widget, width, context, data)
+        })
     }
 
     fn event(
@@ -61,7 +264,9 @@ where
         self.children
             .iter_mut()
             .rev()
-            .filter_map(|c| TypedWidget::<T, B>::event(c, origin, size, data, event.clone()))
+            .filter_map(|c| {
+                TypedWidget::<T, B>::event(&mut c.widget, origin, size, data, event.clone())
+            })
             .next()
     }
 }
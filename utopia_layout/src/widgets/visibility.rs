@@ -0,0 +1,128 @@
+use utopia_core::{
+    lens::Lens,
+    math::{Size, Vector2},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+
+/// Whether a widget is shown, and whether it takes space when it isn't
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+pub enum VisibilityState {
+    #[default]
+    Visible,
+    /// Keeps its space in the layout, but isn't drawn and doesn't receive events
+    Hidden,
+    /// Takes no space, and is neither laid out, drawn nor given events
+    Collapsed,
+}
+
+/// A Widget that shows, hides or collapses its child.
+///
+/// The state is read from the data through a lens. Hiding takes effect on the next draw,
+/// while collapsing changes the layout and takes effect once laid out again.
+pub struct Visibility<T, L, B: Backend> {
+    widget: WidgetPod<T, B>,
+    lens: L,
+    /// State read by the last layout
+    state: VisibilityState,
+}
+
+impl<T, L: Lens<T, VisibilityState>, B: Backend> Visibility<T, L, B> {
+    pub fn new<TW: TypedWidget<T, B> + 'static>(widget: TW, lens: L) -> Self {
+        Visibility {
+            widget: WidgetPod::new(widget),
+            lens,
+            state: VisibilityState::default(),
+        }
+    }
+
+    fn collapsed(&self, data: &T) -> bool {
+        self.lens.with(data, |state| *state) == VisibilityState::Collapsed
+    }
+
+    /// Whether the child is drawn and given events.
+    ///
+    /// A child collapsed by the last layout has no layout to be shown with yet
+    fn shown(&self, data: &T) -> bool {
+        self.lens.with(data, |state| *state) == VisibilityState::Visible
+            && self.state != VisibilityState::Collapsed
+    }
+}
+
+impl<T, L: Lens<T, VisibilityState>, B: Backend> Widget<T> for Visibility<T, L, B> {
+    type Primitive = B::Primitive;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        self.state = self.lens.with(data, |state| *state);
+        match self.state {
+            VisibilityState::Collapsed => bc.constrain(Size::ZERO),
+            _ => TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data),
+        }
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.collapsed(data) {
+            true => 0.,
+            false => {
+                TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+            }
+        }
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.collapsed(data) {
+            true => 0.,
+            false => {
+                TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+            }
+        }
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.collapsed(data) {
+            true => 0.,
+            false => {
+                TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+            }
+        }
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.collapsed(data) {
+            true => 0.,
+            false => {
+                TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+            }
+        }
+    }
+
+    fn baseline(&self, context: &Self::Context, data: &T) -> Option<f32> {
+        match self.state {
+            VisibilityState::Collapsed => None,
+            _ => TypedWidget::<T, B>::baseline(&self.widget, context, data),
+        }
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        match self.shown(data) {
+            true => TypedWidget::<T, B>::draw(&self.widget, origin, size, data),
+            false => CommonPrimitive::None.into(),
+        }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        match self.shown(data) {
+            true => TypedWidget::<T, B>::event(&mut self.widget, origin, size, data, event),
+            false => None,
+        }
+    }
+}
@@ -7,6 +7,7 @@ use utopia_nannou::{
     interface::NannouInterface,
     widgets::{Flex, Text, WidgetExt},
 };
+use utopia_scroll::kinetic::Kinetic;
 
 fn main() {
     NannouInterface::run(model)
@@ -19,6 +20,7 @@ fn model(app: &App) -> NannouInterface<&'static str> {
     let widget = (0..30)
         .fold(Flex::column(), |flex, _| flex.add(Text::new()))
         .scroll()
+        .kinetic(Kinetic::default())
         .vertical(VerticalScrollbar::default().component())
         .border()
         .max_size(SizeConstraint {
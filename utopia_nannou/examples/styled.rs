@@ -48,7 +48,7 @@ fn model(app: &App) -> NannouInterface<MyState> {
             Text::new()
                 .lens(lens_red)
                 .padding()
-                .all(5)
+                .all(5.)
                 .border()
                 .controlled(Click::new(on_click_red)),
         )
@@ -56,7 +56,7 @@ fn model(app: &App) -> NannouInterface<MyState> {
             Text::new()
                 .lens(lens_green)
                 .padding()
-                .all(5)
+                .all(5.)
                 .border()
                 .controlled(Click::new(on_click_green)),
         )
@@ -64,7 +64,7 @@ fn model(app: &App) -> NannouInterface<MyState> {
             Text::new()
                 .lens(lens_blue)
                 .padding()
-                .all(5)
+                .all(5.)
                 .border()
                 .controlled(Click::new(on_click_blue)),
         );
@@ -73,7 +73,7 @@ fn model(app: &App) -> NannouInterface<MyState> {
         .lens(lens_text)
         .styled(lens_color, text_color)
         .padding()
-        .all(10)
+        .all(10.)
         .min_size(SizeConstraint {
             width: ValueConstraint::Percent(300.),
             height: ValueConstraint::Unconstrained,
@@ -1,71 +1,44 @@
-use utopia_core::{component::Component, controllers::click::Click, widgets::pod::WidgetPod};
-use utopia_layout::{
-    spacer::{Axis, Spacer},
-    SizeConstraint, ValueConstraint,
-};
+use utopia_core::{component::Component, widgets::pod::WidgetPod};
 use utopia_scroll::widgets::scrollable::ScrollableState;
 
-use crate::{
-    widgets::{Flex, Label, WidgetExt},
-    NannouBackend,
-};
+use crate::{widgets::Scrollbar, NannouBackend};
 
 pub struct VerticalScrollbar {
-    onclick_offset: f32,
+    thickness: f32,
 }
 
 impl Default for VerticalScrollbar {
     fn default() -> Self {
-        VerticalScrollbar { onclick_offset: 5. }
+        VerticalScrollbar { thickness: 20. }
     }
 }
 
 impl Component<ScrollableState, NannouBackend> for VerticalScrollbar {
     fn component(self) -> WidgetPod<ScrollableState, NannouBackend> {
-        let VerticalScrollbar { onclick_offset } = self;
-
-        let click_up = move |input: &mut ScrollableState| {
-            input.offset_y -= onclick_offset;
-        };
+        Scrollbar::vertical()
+            .thickness(self.thickness)
+            .track_color(nannou::color::LIGHTGRAY)
+            .thumb_color(nannou::color::GRAY)
+            .component()
+    }
+}
 
-        let click_down = move |input: &mut ScrollableState| {
-            input.offset_y += onclick_offset;
-        };
+pub struct HorizontalScrollbar {
+    thickness: f32,
+}
 
-        let widget = Flex::column()
-            .add(
-                Label::new("^")
-                    .padding()
-                    .all(5)
-                    .border()
-                    .max_size(SizeConstraint {
-                        width: ValueConstraint::Pixels(20.),
-                        height: ValueConstraint::Unconstrained,
-                    })
-                    .controlled(Click::new(click_up)),
-            )
-            .add_flex(
-                Spacer::new(Axis::Vertical)
-                    .min_size(SizeConstraint {
-                        width: ValueConstraint::Pixels(20.),
-                        height: ValueConstraint::Unconstrained,
-                    })
-                    .background()
-                    .color(nannou::color::GRAY),
-                1,
-            )
-            .add(
-                Label::new("v")
-                    .padding()
-                    .all(5)
-                    .border()
-                    .max_size(SizeConstraint {
-                        width: ValueConstraint::Pixels(20.),
-                        height: ValueConstraint::Unconstrained,
-                    })
-                    .controlled(Click::new(click_down)),
-            );
+impl Default for HorizontalScrollbar {
+    fn default() -> Self {
+        HorizontalScrollbar { thickness: 20. }
+    }
+}
 
-        WidgetPod::new(widget)
+impl Component<ScrollableState, NannouBackend> for HorizontalScrollbar {
+    fn component(self) -> WidgetPod<ScrollableState, NannouBackend> {
+        Scrollbar::horizontal()
+            .thickness(self.thickness)
+            .track_color(nannou::color::LIGHTGRAY)
+            .thumb_color(nannou::color::GRAY)
+            .component()
     }
 }
@@ -1,11 +1,22 @@
 use std::time::Duration;
 
 use utopia_animations::event::AnimateEvent;
-use utopia_core::controllers::{click::MouseClickEvent, TransformEvent};
+use utopia_core::{
+    controllers::{
+        click::{MouseButton, MouseClickEvent},
+        drag::DragEvent,
+        wheel::MouseWheelEvent,
+        TransformEvent,
+    },
+    math::Vector2,
+};
 
 #[derive(Debug, Clone)]
 pub enum NannouEvent {
     MouseClick(MouseClickEvent),
+    MouseMove(Vector2),
+    MouseRelease(MouseClickEvent),
+    MouseWheel(MouseWheelEvent),
     Update(Duration),
 }
 
@@ -24,6 +35,32 @@ impl TransformEvent<MouseClickEvent> for NannouEvent {
     }
 }
 
+impl TransformEvent<DragEvent> for NannouEvent {
+    fn transform_event(self) -> Option<DragEvent> {
+        match self {
+            NannouEvent::MouseClick(MouseClickEvent {
+                pos,
+                mouse_button: MouseButton::Left,
+            }) => Some(DragEvent::Pressed(pos)),
+            NannouEvent::MouseMove(pos) => Some(DragEvent::Moved(pos)),
+            NannouEvent::MouseRelease(MouseClickEvent {
+                pos,
+                mouse_button: MouseButton::Left,
+            }) => Some(DragEvent::Released(pos)),
+            _ => None,
+        }
+    }
+}
+
+impl TransformEvent<MouseWheelEvent> for NannouEvent {
+    fn transform_event(self) -> Option<MouseWheelEvent> {
+        match self {
+            NannouEvent::MouseWheel(wheel) => Some(wheel),
+            _ => None,
+        }
+    }
+}
+
 impl TransformEvent<AnimateEvent> for NannouEvent {
     fn transform_event(self) -> Option<AnimateEvent> {
         match self {
@@ -1,10 +1,10 @@
 use nannou::{
-    event::{MouseButton, WindowEvent},
+    event::{MouseButton, MouseScrollDelta, WindowEvent},
     geom::Vector3,
     App, Event, Frame,
 };
 use utopia_core::{
-    controllers::click::MouseClickEvent,
+    controllers::{click::MouseClickEvent, wheel::MouseWheelEvent},
     interface::Interface,
     math::{Size, Vector2},
     widgets::TypedWidget,
@@ -12,6 +12,9 @@ use utopia_core::{
 
 use crate::{event::NannouEvent, NannouBackend};
 
+/// Distance scrolled by one notch of a mouse wheel
+const WHEEL_LINE_HEIGHT: f32 = 20.;
+
 pub struct NannouInterface<T> {
     interface: Interface<T, NannouBackend>,
     backend: NannouBackend,
@@ -24,6 +27,13 @@ pub struct MouseState {
     pos: Vector2,
 }
 
+impl MouseState {
+    /// Position of the cursor in UI coordinates (origin at the top-left corner)
+    fn ui_pos(&self, win_height: f32) -> Vector2 {
+        Vector2::new(self.pos.x, win_height - self.pos.y)
+    }
+}
+
 impl<T: 'static> NannouInterface<T> {
     pub fn new<W: TypedWidget<T, NannouBackend> + 'static>(
         widget: W,
@@ -32,9 +42,11 @@ impl<T: 'static> NannouInterface<T> {
     ) -> NannouInterface<T> {
         let mut interface = Interface::new(widget);
         interface.resize(size);
+        let mut backend = NannouBackend::default();
+        backend.set_viewport(size);
         NannouInterface {
             interface,
-            backend: NannouBackend::default(),
+            backend,
             data,
             mouse_state: MouseState::default(),
         }
@@ -67,6 +79,8 @@ impl<T: 'static> NannouInterface<T> {
                         WindowEvent::MouseMoved(pos) => {
                             state.mouse_state.pos =
                                 Vector2::new(pos.x + win_rect.w() / 2., pos.y + win_rect.h() / 2.);
+                            let pos = state.mouse_state.ui_pos(win_rect.h());
+                            state.interface.add_event(NannouEvent::MouseMove(pos))
                         }
                         WindowEvent::MousePressed(button) => {
                             if button != MouseButton::Left {
@@ -77,10 +91,41 @@ impl<T: 'static> NannouInterface<T> {
                                 .add_event(NannouEvent::MouseClick(MouseClickEvent {
                                     mouse_button:
                                         utopia_core::controllers::click::MouseButton::Left,
-                                    pos: utopia_core::math::Vector2::new(
-                                        state.mouse_state.pos.x,
-                                        win_rect.h() - state.mouse_state.pos.y,
-                                    ),
+                                    pos: state.mouse_state.ui_pos(win_rect.h()),
+                                }))
+                        }
+                        WindowEvent::MouseReleased(button) => {
+                            if button != MouseButton::Left {
+                                return;
+                            }
+                            state
+                                .interface
+                                .add_event(NannouEvent::MouseRelease(MouseClickEvent {
+                                    mouse_button:
+                                        utopia_core::controllers::click::MouseButton::Left,
+                                    pos: state.mouse_state.ui_pos(win_rect.h()),
+                                }))
+                        }
+                        WindowEvent::Resized(size) => {
+                            let size = Size::new(size.x, size.y);
+                            state.interface.resize(size);
+                            state.backend.set_viewport(size);
+                        }
+                        WindowEvent::MouseWheel(delta, _phase) => {
+                            // winit deltas are positive when scrolling up or right
+                            let delta = match delta {
+                                MouseScrollDelta::LineDelta(x, y) => {
+                                    Vector2::new(x * WHEEL_LINE_HEIGHT, -y * WHEEL_LINE_HEIGHT)
+                                }
+                                MouseScrollDelta::PixelDelta(pos) => {
+                                    Vector2::new(pos.x as f32, -pos.y as f32)
+                                }
+                            };
+                            state
+                                .interface
+                                .add_event(NannouEvent::MouseWheel(MouseWheelEvent {
+                                    pos: state.mouse_state.ui_pos(win_rect.h()),
+                                    delta,
                                 }))
                         }
                         _ => (),
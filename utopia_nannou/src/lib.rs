@@ -1,7 +1,12 @@
 use crate::font::Font;
 use nannou::{geom::rect::Rect, text::Builder as TextBuilder, wgpu::Texture};
-use utopia_core::{contexts::ContextProvider, math::Size, Backend};
+use utopia_core::{
+    contexts::ContextProvider,
+    math::{LayoutDirection, Size},
+    Backend,
+};
 use utopia_image::context::ImageContext;
+use utopia_layout::LayoutUnits;
 use utopia_text::context::MeasureBrush;
 
 pub mod components;
@@ -15,19 +20,31 @@ pub mod widgets;
 pub struct NannouBackend {
     measure_brush: MeasureBrush<Font>,
     image_context: ImageContext<Texture>,
+    units: LayoutUnits,
+    direction: LayoutDirection,
 }
 
 impl Default for NannouBackend {
     fn default() -> Self {
         let measure_brush = MeasureBrush {
             measure: Box::new(measure),
+            ascent: Box::new(ascent),
+            font_size: 16,
         };
         let image_context = ImageContext {
             measure: Box::new(measure_image),
         };
+        let rem = measure_brush.em(Font::Default, 16);
+        let em = measure_brush.em(Font::Default, measure_brush.font_size);
         NannouBackend {
             measure_brush,
             image_context,
+            units: LayoutUnits {
+                viewport: Size::ZERO,
+                em,
+                rem,
+            },
+            direction: LayoutDirection::default(),
         }
     }
 }
@@ -38,6 +55,28 @@ impl Backend for NannouBackend {
     type EventReaction = reaction::NannouReaction;
 }
 
+impl NannouBackend {
+    /// Sets the size that viewport-relative units are resolved against
+    pub fn set_viewport(&mut self, size: Size) {
+        self.units.viewport = size;
+    }
+
+    /// Sets the font size of the current text, that `em` units are resolved against.
+    ///
+    /// Layouts cached before are kept until `Interface::request_layout` is called
+    pub fn set_font_size(&mut self, font_size: u16) {
+        self.measure_brush.font_size = font_size;
+        self.units.em = self.measure_brush.em(Font::Default, font_size);
+    }
+
+    /// Sets the direction in which layouts flow horizontally.
+    ///
+    /// Call `Interface::request_layout` afterwards, as layouts cached before keep the previous direction
+    pub fn set_direction(&mut self, direction: LayoutDirection) {
+        self.direction = direction;
+    }
+}
+
 fn measure(contents: &str, font: Font, size: u16) -> Size {
     let text = TextBuilder::from(contents).font_size(size as nannou::text::FontSize);
 
@@ -53,6 +92,11 @@ fn measure(contents: &str, font: Font, size: u16) -> Size {
     }
 }
 
+fn ascent(font: Font, size: u16) -> f32 {
+    let scale = nannou::text::pt_to_scale(size as nannou::text::FontSize);
+    font.resolve().v_metrics(scale).ascent
+}
+
 fn measure_image(img: &Texture) -> Size {
     let [x, y] = img.size();
     Size::new(x as f32, y as f32)
@@ -70,6 +114,18 @@ impl ContextProvider<ImageContext<Texture>> for NannouBackend {
     }
 }
 
+impl ContextProvider<LayoutDirection> for NannouBackend {
+    fn provide(&self) -> &LayoutDirection {
+        &self.direction
+    }
+}
+
+impl ContextProvider<LayoutUnits> for NannouBackend {
+    fn provide(&self) -> &LayoutUnits {
+        &self.units
+    }
+}
+
 impl ContextProvider<()> for NannouBackend {
     fn provide(&self) -> &() {
         &()
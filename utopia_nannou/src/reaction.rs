@@ -1,10 +1,24 @@
-use utopia_core::reactions::CommonReaction;
+use utopia_core::reactions::{CommonReaction, LayoutReaction, MergeReaction, TransformReaction};
+use utopia_scroll::reaction::ScrollReaction;
 
 pub enum NannouReaction {
     Common(CommonReaction),
+    Scroll(ScrollReaction),
+    /// Reactions of a widget and of one of its children
+    Many(Vec<NannouReaction>),
     None,
 }
 
+impl NannouReaction {
+    fn into_vec(self) -> Vec<NannouReaction> {
+        match self {
+            NannouReaction::Many(reactions) => reactions,
+            NannouReaction::None => Vec::new(),
+            reaction => vec![reaction],
+        }
+    }
+}
+
 impl From<()> for NannouReaction {
     fn from(_input: ()) -> Self {
         NannouReaction::None
@@ -16,3 +30,62 @@ impl From<CommonReaction> for NannouReaction {
         NannouReaction::Common(input)
     }
 }
+
+impl From<ScrollReaction> for NannouReaction {
+    fn from(input: ScrollReaction) -> Self {
+        NannouReaction::Scroll(input)
+    }
+}
+
+impl LayoutReaction for NannouReaction {
+    fn requests_layout(&self) -> bool {
+        match self {
+            NannouReaction::Common(common) => common.requests_layout(),
+            NannouReaction::Many(reactions) => reactions.iter().any(Self::requests_layout),
+            _ => false,
+        }
+    }
+
+    fn requests_full_layout(&self) -> bool {
+        match self {
+            NannouReaction::Common(common) => common.requests_full_layout(),
+            NannouReaction::Many(reactions) => reactions.iter().any(Self::requests_full_layout),
+            _ => false,
+        }
+    }
+}
+
+impl TransformReaction<ScrollReaction> for NannouReaction {
+    fn transform_reaction(self) -> (Option<ScrollReaction>, Option<Self>) {
+        match self {
+            NannouReaction::Scroll(reaction) => (Some(reaction), None),
+            NannouReaction::Many(reactions) => {
+                let mut scroll = None;
+                let rest: Vec<NannouReaction> = reactions
+                    .into_iter()
+                    .filter_map(|reaction| match reaction {
+                        NannouReaction::Scroll(reaction) if scroll.is_none() => {
+                            scroll = Some(reaction);
+                            None
+                        }
+                        reaction => Some(reaction),
+                    })
+                    .collect();
+                let rest = match rest.is_empty() {
+                    true => None,
+                    false => Some(NannouReaction::Many(rest)),
+                };
+                (scroll, rest)
+            }
+            reaction => (None, Some(reaction)),
+        }
+    }
+}
+
+impl MergeReaction for NannouReaction {
+    fn merge(self, other: Self) -> Self {
+        let mut reactions = self.into_vec();
+        reactions.extend(other.into_vec());
+        NannouReaction::Many(reactions)
+    }
+}
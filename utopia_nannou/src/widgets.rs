@@ -3,7 +3,10 @@ use std::ops::{Deref, DerefMut};
 use crate::font::Font;
 use nannou::wgpu::Texture;
 use utopia_animations::{
-    widgets::{animated::Animated as AnimatedWidget, AnimationExt},
+    widgets::{
+        animated::Animated as AnimatedWidget,
+        animated_layout::AnimatedLayout as AnimatedLayoutWidget, AnimationExt,
+    },
     CanTween, Linear,
 };
 use utopia_core::{
@@ -21,23 +24,48 @@ use utopia_decorations::widgets::{
 use utopia_image::widgets::image::Image as ImageWidget;
 use utopia_layout::{
     widgets::{
-        align::Align as AlignWidget, flex::Flex as FlexWidget, max_size::MaxSize as MaxSizeWidget,
-        min_size::MinSize as MinSizeWidget, padding::Padding as PaddingWidget,
-        stack::Stack as StackWidget, LayoutExt,
+        align::Align as AlignWidget,
+        aspect_ratio::AspectRatio as AspectRatioWidget,
+        constraint_layout::ConstraintLayout as ConstraintLayoutWidget,
+        flex::Flex as FlexWidget,
+        grid::Grid as GridWidget,
+        max_size::MaxSize as MaxSizeWidget,
+        min_size::MinSize as MinSizeWidget,
+        padding::Padding as PaddingWidget,
+        responsive::Responsive as ResponsiveWidget,
+        sized_box::SizedBox as SizedBoxWidget,
+        split::Split as SplitWidget,
+        stack::Stack as StackWidget,
+        visibility::{Visibility as VisibilityWidget, VisibilityState},
+        LayoutExt,
     },
     SizeConstraint,
 };
-use utopia_scroll::widgets::scrollview::ScrollView as ScrollViewWidget;
+use utopia_scroll::widgets::{
+    ensure_visible::EnsureVisible as EnsureVisibleWidget, scrollbar::Scrollbar as ScrollbarWidget,
+    scrollview::ScrollView as ScrollViewWidget,
+    sticky_headers::StickyHeaders as StickyHeadersWidget,
+    virtual_list::VirtualList as VirtualListWidget,
+};
+use utopia_table::widgets::table::{Table as TableWidget, TableColumn as TableColumnWidget};
+use utopia_tabs::widgets::tabs::Tabs as TabsWidget;
 use utopia_text::widgets::{label::Label as LabelWidget, text::Text as TextWidget};
 
 use crate::NannouBackend;
 
 pub type Align<T> = AlignWidget<T, NannouBackend>;
+pub type AspectRatio<T> = AspectRatioWidget<T, NannouBackend>;
 pub type Color = nannou::color::Srgb<u8>;
+pub type ConstraintLayout<T> = ConstraintLayoutWidget<T, NannouBackend>;
 pub type Controlled<T, W, C> = ControlledWidget<T, W, C, NannouBackend>;
+pub type EnsureVisible<T> = EnsureVisibleWidget<T, NannouBackend>;
 pub type Image = ImageWidget<Texture>;
 pub type NannouWidgetPod<T> = WidgetPod<T, NannouBackend>;
 pub type Flex<T> = FlexWidget<T, NannouBackend>;
+pub type Grid<T> = GridWidget<T, NannouBackend>;
+pub type Table<R> = TableWidget<R, Color, NannouBackend>;
+pub type TableColumn<R> = TableColumnWidget<R, NannouBackend>;
+pub type Tabs<T, L> = TabsWidget<T, L, Color, NannouBackend>;
 pub type Text = TextWidget<Font, Color>;
 pub type Label = LabelWidget<Font, Color>;
 pub type Border<T> = BorderWidget<T, Color, NannouBackend>;
@@ -46,12 +74,20 @@ pub type LensWrap<T, U, L, W> = LensWrapWidget<T, U, L, W, NannouBackend>;
 pub type Padding<T> = PaddingWidget<T, NannouBackend>;
 pub type MinSize<T> = MinSizeWidget<T, NannouBackend>;
 pub type MaxSize<T> = MaxSizeWidget<T, NannouBackend>;
+pub type Responsive<T> = ResponsiveWidget<T, NannouBackend>;
+pub type SizedBox<T> = SizedBoxWidget<T, NannouBackend>;
+pub type Split<T, L> = SplitWidget<T, L, Color, NannouBackend>;
 pub type Styled<U, L, LW, W, TW> = StyledWidget<U, L, LW, W, TW, NannouBackend>;
 pub type ScrollView<T> = ScrollViewWidget<T, NannouBackend>;
+pub type Scrollbar = ScrollbarWidget<Color>;
 pub type Stack<T> = StackWidget<T, NannouBackend>;
+pub type StickyHeaders<T> = StickyHeadersWidget<T, NannouBackend>;
 pub type Scale<T> = ScaleWidget<T, NannouBackend>;
+pub type VirtualList<U> = VirtualListWidget<U, NannouBackend>;
+pub type Visibility<T, L> = VisibilityWidget<T, L, NannouBackend>;
 pub type Animated<T, U, L, EF, TW, W, LTU = NoLens> =
     AnimatedWidget<T, U, L, EF, TW, W, NannouBackend, LTU>;
+pub type AnimatedLayout<T, TW, EF = Linear> = AnimatedLayoutWidget<T, TW, EF, NannouBackend>;
 
 pub trait WidgetExt<T>: TypedWidget<T, NannouBackend> + Sized + 'static {
     // ----
@@ -78,6 +114,18 @@ pub trait WidgetExt<T>: TypedWidget<T, NannouBackend> + Sized + 'static {
         MaxSize::new(self, constraint)
     }
 
+    fn sized(self, constraint: SizeConstraint) -> SizedBox<T> {
+        SizedBox::new(self, constraint)
+    }
+
+    fn aspect_ratio(self, ratio: f32) -> AspectRatio<T> {
+        AspectRatio::new(self, ratio)
+    }
+
+    fn visibility<L: Lens<T, VisibilityState>>(self, lens: L) -> Visibility<T, L> {
+        Visibility::new(self, lens)
+    }
+
     // ----
     // ScrollExt
     // ----
@@ -86,6 +134,10 @@ pub trait WidgetExt<T>: TypedWidget<T, NannouBackend> + Sized + 'static {
         ScrollView::new(self)
     }
 
+    fn ensure_visible<F: Fn(&T) -> bool + 'static>(self, predicate: F) -> EnsureVisible<T> {
+        EnsureVisible::new(self, predicate)
+    }
+
     // ----
     // DecorationsExt
     // ----
@@ -157,6 +209,10 @@ pub trait WidgetExt<T>: TypedWidget<T, NannouBackend> + Sized + 'static {
     {
         AnimationExt::animate_from_data(self, lens, target)
     }
+
+    fn animate_layout(self) -> AnimatedLayout<T, Self> {
+        AnimatedLayout::new(self)
+    }
 }
 
 pub trait LensExt<T>: Sized + 'static {
@@ -0,0 +1,122 @@
+/// Velocities below this threshold (in pixels per second) stop the motion
+const MIN_VELOCITY: f32 = 5.;
+/// Longest time step used to integrate the motion, to keep the spring stable on slow frames
+const MAX_STEP: f32 = 1. / 60.;
+
+/// Momentum scrolling settings
+#[derive(Debug, Clone)]
+pub struct Kinetic {
+    /// Exponential decay of the velocity, per second
+    pub friction: f32,
+    /// Stiffness of the spring pulling overscrolled content back. `0.` disables overscrolling
+    pub bounce: f32,
+    /// How far the content can be pulled past its edges, in pixels
+    pub max_overscroll: f32,
+    /// Whether the content itself can be dragged, as on a touch screen
+    pub drag_content: bool,
+}
+
+impl Default for Kinetic {
+    fn default() -> Self {
+        Kinetic {
+            friction: 4.,
+            bounce: 150.,
+            max_overscroll: 100.,
+            drag_content: true,
+        }
+    }
+}
+
+impl Kinetic {
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    pub fn bounce(mut self, bounce: f32) -> Self {
+        self.bounce = bounce;
+        self
+    }
+
+    pub fn max_overscroll(mut self, max_overscroll: f32) -> Self {
+        self.max_overscroll = max_overscroll;
+        self
+    }
+
+    pub fn drag_content(mut self, drag_content: bool) -> Self {
+        self.drag_content = drag_content;
+        self
+    }
+
+    pub fn allows_overscroll(&self) -> bool {
+        self.bounce > 0. && self.max_overscroll > 0.
+    }
+
+    /// Initial velocity for the content to travel exactly `distance` before stopping
+    pub fn velocity_for(&self, distance: f32) -> f32 {
+        distance * self.friction
+    }
+
+    /// Moves an offset by `distance` while it is dragged,
+    /// resisting when pulled past the edges of `0..=max_offset`
+    pub fn drag(&self, offset: f32, distance: f32, max_offset: f32) -> f32 {
+        let target = offset + distance;
+        if !self.allows_overscroll() {
+            return target.max(0.).min(max_offset);
+        }
+        let resisted = match (target < 0., target > max_offset) {
+            (true, _) if distance < 0. => offset + distance / 2.,
+            (_, true) if distance > 0. => offset + distance / 2.,
+            _ => target,
+        };
+        resisted
+            .max(-self.max_overscroll)
+            .min(max_offset + self.max_overscroll)
+    }
+
+    /// Advances the motion of one axis by `elapsed` seconds.
+    ///
+    /// Returns the new offset and velocity.
+    pub fn step(&self, offset: f32, velocity: f32, max_offset: f32, elapsed: f32) -> (f32, f32) {
+        let (mut offset, mut velocity) = (offset, velocity);
+        let mut remaining = elapsed;
+        while remaining > 0. {
+            let dt = remaining.min(MAX_STEP);
+            remaining -= dt;
+
+            let overshoot = match (offset < 0., offset > max_offset) {
+                (true, _) => offset,
+                (_, true) => offset - max_offset,
+                _ => 0.,
+            };
+            if overshoot != 0. && self.allows_overscroll() {
+                // Critically damped spring, brings the content back without oscillating
+                let damping = 2. * self.bounce.sqrt();
+                velocity += (-self.bounce * overshoot - damping * velocity) * dt;
+            } else {
+                velocity *= (-self.friction * dt).exp();
+            }
+            offset += velocity * dt;
+
+            if !self.allows_overscroll() {
+                if offset < 0. || offset > max_offset {
+                    velocity = 0.;
+                }
+                offset = offset.max(0.).min(max_offset);
+            } else if overshoot < 0. && offset >= 0. || overshoot > 0. && offset <= max_offset {
+                // Back in range after a bounce
+                offset = offset.max(0.).min(max_offset);
+                velocity = 0.;
+            } else {
+                offset = offset
+                    .max(-self.max_overscroll)
+                    .min(max_offset + self.max_overscroll);
+            }
+
+            if velocity.abs() < MIN_VELOCITY && offset >= 0. && offset <= max_offset {
+                velocity = 0.;
+            }
+        }
+        (offset, velocity)
+    }
+}
@@ -1,2 +1,5 @@
+pub mod kinetic;
+pub mod motion;
 pub mod primitive;
+pub mod reaction;
 pub mod widgets;
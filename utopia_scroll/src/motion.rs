@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use utopia_animations::{event::AnimateEvent, CanTween, EasingFunction};
+use utopia_core::{
+    controllers::{drag::DragEvent, wheel::MouseWheelEvent, TransformEvent},
+    math::{Rectangle, Size, Vector2},
+};
+
+use crate::{kinetic::Kinetic, reaction::ScrollReaction, widgets::scrollable::ScrollableState};
+
+/// Moves the offsets of a `ScrollableState` : programmatic scrolls, mouse wheel and momentum.
+///
+/// Shared by the widgets that own a scrolled viewport.
+#[derive(Default)]
+pub struct ScrollMotion {
+    transition: Option<ScrollTransition>,
+    animation: Option<ScrollAnimation>,
+    kinetic: Option<Kinetic>,
+    velocity: Vector2,
+    /// Last pointer position while the content is dragged
+    drag: Option<Vector2>,
+    /// Distance the content was dragged by since the last tick
+    dragged: Vector2,
+}
+
+/// How programmatic scrolls are animated
+struct ScrollTransition {
+    duration: Duration,
+    easing: Box<dyn EasingFunction>,
+}
+
+/// A programmatic scroll in progress
+struct ScrollAnimation {
+    from: Vector2,
+    to: Vector2,
+    elapsed: Duration,
+}
+
+impl ScrollMotion {
+    /// Animates programmatic scrolls over `duration`, following `easing`
+    pub fn set_transition<EF: EasingFunction + 'static>(&mut self, duration: Duration, easing: EF) {
+        self.transition = Some(ScrollTransition {
+            duration,
+            easing: Box::new(easing),
+        });
+    }
+
+    /// Enables momentum scrolling
+    pub fn set_kinetic(&mut self, kinetic: Kinetic) {
+        self.kinetic = Some(kinetic);
+    }
+
+    /// Whether the offsets may temporarily leave the scrollable range
+    pub fn allows_overscroll(&self) -> bool {
+        self.kinetic
+            .as_ref()
+            .map(|kinetic| kinetic.allows_overscroll())
+            .unwrap_or(false)
+    }
+
+    /// Stops any scrolling motion in progress
+    pub fn stop(&mut self) {
+        self.animation = None;
+        self.velocity = Vector2::ZERO;
+        self.drag = None;
+        self.dragged = Vector2::ZERO;
+    }
+
+    /// Scrolls to an absolute offset of the content
+    pub fn scroll_to(&mut self, state: &mut ScrollableState, offset: Vector2) {
+        let to = state.clamp_offset(offset);
+        self.stop();
+        match self.transition {
+            Some(_) => {
+                self.animation = Some(ScrollAnimation {
+                    from: state.offset(),
+                    to,
+                    elapsed: Duration::default(),
+                })
+            }
+            None => {
+                state.offset_x = to.x;
+                state.offset_y = to.y;
+            }
+        }
+    }
+
+    /// Scrolls as little as possible for `rect`, in content coordinates, to be fully visible
+    pub fn scroll_to_rect(&mut self, state: &mut ScrollableState, rect: Rectangle) {
+        let offset = state.offset_showing(rect);
+        self.scroll_to(state, offset)
+    }
+
+    /// Applies a request coming from the content of a viewport located at `origin`
+    pub fn handle_reaction(
+        &mut self,
+        state: &mut ScrollableState,
+        reaction: ScrollReaction,
+        origin: Vector2,
+    ) {
+        match reaction {
+            ScrollReaction::ScrollTo(offset) => self.scroll_to(state, offset),
+            ScrollReaction::ScrollToRect(rect) => self.scroll_to_rect(state, rect),
+            ScrollReaction::EnsureVisible(rect) => {
+                // The content is drawn at our origin, shifted by the current offset
+                let content_origin = origin - state.offset();
+                self.scroll_to_rect(
+                    state,
+                    Rectangle {
+                        origin: rect.origin - content_origin,
+                        size: rect.size,
+                    },
+                )
+            }
+        }
+    }
+
+    /// Handles the events moving a viewport located at `origin`
+    pub fn event<E>(&mut self, state: &mut ScrollableState, origin: Vector2, size: Size, event: &E)
+    where
+        E: Clone
+            + TransformEvent<AnimateEvent>
+            + TransformEvent<DragEvent>
+            + TransformEvent<MouseWheelEvent>,
+    {
+        if let Some(AnimateEvent { elapsed }) =
+            TransformEvent::<AnimateEvent>::transform_event(event.clone())
+        {
+            self.tick(state, elapsed);
+        }
+        if let Some(MouseWheelEvent { pos, delta }) =
+            TransformEvent::<MouseWheelEvent>::transform_event(event.clone())
+        {
+            if size.contains(pos - origin) {
+                self.wheel(state, delta);
+            }
+        }
+        if let Some(drag) = TransformEvent::<DragEvent>::transform_event(event.clone()) {
+            self.drag(state, drag, origin, size);
+        }
+    }
+
+    fn wheel(&mut self, state: &mut ScrollableState, delta: Vector2) {
+        self.animation = None;
+        match &self.kinetic {
+            Some(kinetic) => {
+                self.velocity = self.velocity
+                    + Vector2::new(kinetic.velocity_for(delta.x), kinetic.velocity_for(delta.y));
+            }
+            None => {
+                state.offset_x += delta.x;
+                state.offset_y += delta.y;
+                state.clamp_offsets();
+            }
+        }
+    }
+
+    fn drag(&mut self, state: &mut ScrollableState, event: DragEvent, origin: Vector2, size: Size) {
+        let kinetic = match &self.kinetic {
+            Some(kinetic) if kinetic.drag_content => kinetic,
+            _ => return,
+        };
+
+        match (event, self.drag) {
+            (DragEvent::Pressed(pos), _) if size.contains(pos - origin) => {
+                self.stop();
+                self.drag = Some(pos);
+            }
+            (DragEvent::Moved(pos), Some(last)) => {
+                // The content follows the pointer
+                let distance = last - pos;
+                state.offset_x = kinetic.drag(state.offset_x, distance.x, state.max_offset_x());
+                state.offset_y = kinetic.drag(state.offset_y, distance.y, state.max_offset_y());
+                self.dragged = self.dragged + distance;
+                self.drag = Some(pos);
+            }
+            (DragEvent::Released(_), Some(_)) => self.drag = None,
+            _ => (),
+        }
+    }
+
+    fn tick(&mut self, state: &mut ScrollableState, elapsed: Duration) {
+        if let (Some(transition), Some(animation)) = (&self.transition, &mut self.animation) {
+            animation.elapsed = (animation.elapsed + elapsed).min(transition.duration);
+            let time_factor = match transition.duration.as_secs_f64() > 0. {
+                true => animation.elapsed.as_secs_f64() / transition.duration.as_secs_f64(),
+                false => 1.,
+            };
+            let progress = transition.easing.y(time_factor);
+            state.offset_x = f32::ease(animation.from.x, animation.to.x, progress);
+            state.offset_y = f32::ease(animation.from.y, animation.to.y, progress);
+
+            if animation.elapsed >= transition.duration {
+                self.animation = None;
+            }
+            return;
+        }
+
+        let kinetic = match &self.kinetic {
+            Some(kinetic) => kinetic,
+            None => return,
+        };
+        let elapsed = elapsed.as_secs_f32();
+        if self.drag.is_some() {
+            // Released content keeps the velocity of the last frame
+            if elapsed > 0. {
+                self.velocity = Vector2::new(self.dragged.x / elapsed, self.dragged.y / elapsed);
+            }
+            self.dragged = Vector2::ZERO;
+            return;
+        }
+
+        let (offset_x, velocity_x) = kinetic.step(
+            state.offset_x,
+            self.velocity.x,
+            state.max_offset_x(),
+            elapsed,
+        );
+        let (offset_y, velocity_y) = kinetic.step(
+            state.offset_y,
+            self.velocity.y,
+            state.max_offset_y(),
+            elapsed,
+        );
+        state.offset_x = offset_x;
+        state.offset_y = offset_y;
+        self.velocity = Vector2::new(velocity_x, velocity_y);
+    }
+}
@@ -0,0 +1,13 @@
+use utopia_core::math::{Rectangle, Vector2};
+
+/// Requests handled by the closest enclosing `ScrollView`
+#[derive(Debug, Clone)]
+pub enum ScrollReaction {
+    /// Scroll to an absolute offset of the content
+    ScrollTo(Vector2),
+    /// Scroll as little as possible for a rectangle of the content to be fully visible
+    ScrollToRect(Rectangle),
+    /// Scroll as little as possible for a rectangle, given in screen coordinates,
+    /// to be fully visible
+    EnsureVisible(Rectangle),
+}
@@ -0,0 +1,87 @@
+use utopia_core::{
+    math::{Rectangle, Size, Vector2},
+    reactions::{self, MergeReaction},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints,
+};
+
+use crate::reaction::ScrollReaction;
+
+/// A Widget that asks the enclosing `ScrollView` to bring it fully into view
+/// whenever `predicate` becomes true, e.g. when its list entry gets selected.
+pub struct EnsureVisible<T, B: Backend> {
+    widget: WidgetPod<T, B>,
+    predicate: Box<dyn Fn(&T) -> bool>,
+    requested: bool,
+}
+
+impl<T, B: Backend> EnsureVisible<T, B> {
+    pub fn new<TW: TypedWidget<T, B> + 'static, F: Fn(&T) -> bool + 'static>(
+        widget: TW,
+        predicate: F,
+    ) -> Self {
+        EnsureVisible {
+            widget: WidgetPod::new(widget),
+            predicate: Box::new(predicate),
+            requested: false,
+        }
+    }
+}
+
+impl<T, B: Backend> Widget<T> for EnsureVisible<T, B>
+where
+    B::EventReaction: From<ScrollReaction> + MergeReaction,
+{
+    type Primitive = B::Primitive;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        TypedWidget::<T, B>::layout(&mut self.widget, bc, context, data)
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.widget, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.widget, width, context, data)
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        TypedWidget::<T, B>::draw(&self.widget, origin, size, data)
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        // The child may be what makes the predicate true, e.g. when clicked
+        let reaction = TypedWidget::<T, B>::event(&mut self.widget, origin, size, data, event);
+
+        let scroll_reaction = match ((self.predicate)(data), self.requested) {
+            (true, false) => {
+                self.requested = true;
+                Some(ScrollReaction::EnsureVisible(Rectangle { origin, size }).into())
+            }
+            (false, _) => {
+                self.requested = false;
+                None
+            }
+            (true, true) => None,
+        };
+        reactions::merge(reaction, scroll_reaction)
+    }
+}
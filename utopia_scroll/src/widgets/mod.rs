@@ -1,13 +1,22 @@
+use ensure_visible::EnsureVisible;
 use scrollview::ScrollView;
 use utopia_core::{widgets::TypedWidget, Backend};
 
+pub mod ensure_visible;
 pub mod scrollable;
+pub mod scrollbar;
 pub mod scrollview;
+pub mod sticky_headers;
+pub mod virtual_list;
 
 pub trait ScrollExt<T, B: Backend>: TypedWidget<T, B> + Sized + 'static {
     fn scroll(self) -> ScrollView<T, B> {
         ScrollView::new(self)
     }
+
+    fn ensure_visible<F: Fn(&T) -> bool + 'static>(self, predicate: F) -> EnsureVisible<T, B> {
+        EnsureVisible::new(self, predicate)
+    }
 }
 
 impl<T, B: Backend, W: TypedWidget<T, B> + 'static> ScrollExt<T, B> for W {}
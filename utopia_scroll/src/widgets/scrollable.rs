@@ -1,10 +1,17 @@
+use std::time::Duration;
+
+use utopia_animations::{event::AnimateEvent, EasingFunction};
 use utopia_core::{
-    math::{Size, Vector2},
+    controllers::{drag::DragEvent, wheel::MouseWheelEvent, TransformEvent},
+    math::{Rectangle, Size, Vector2},
+    reactions::TransformReaction,
     widgets::{pod::WidgetPod, TypedWidget, Widget},
     Backend, BoxConstraints, CommonPrimitive,
 };
 
-use crate::primitive::ClipPrimitive;
+use crate::{
+    kinetic::Kinetic, motion::ScrollMotion, primitive::ClipPrimitive, reaction::ScrollReaction,
+};
 
 #[derive(Debug, Default, Clone)]
 pub struct ScrollableState {
@@ -13,7 +20,76 @@ pub struct ScrollableState {
     scroll_x: ScrollType,
     scroll_y: ScrollType,
     /// Read-only (changing this won't affect the size of child_size)
-    child_size: Size,
+    pub(crate) child_size: Size,
+    /// Read-only, size of the visible area
+    pub(crate) viewport_size: Size,
+}
+
+impl ScrollableState {
+    /// Size of the scrolled content, as computed during the last layout
+    pub fn child_size(&self) -> Size {
+        self.child_size
+    }
+
+    /// Size of the visible area, as computed during the last layout
+    pub fn viewport_size(&self) -> Size {
+        self.viewport_size
+    }
+
+    pub fn max_offset_x(&self) -> f32 {
+        (self.child_size.width - self.viewport_size.width).max(0.)
+    }
+
+    pub fn max_offset_y(&self) -> f32 {
+        (self.child_size.height - self.viewport_size.height).max(0.)
+    }
+
+    pub fn offset(&self) -> Vector2 {
+        Vector2::new(self.offset_x, self.offset_y)
+    }
+
+    /// Keeps the offsets within the scrollable range
+    pub fn clamp_offsets(&mut self) {
+        self.offset_x = self.offset_x.max(0.).min(self.max_offset_x());
+        self.offset_y = self.offset_y.max(0.).min(self.max_offset_y());
+    }
+
+    /// Returns the given offset, kept within the scrollable range
+    pub fn clamp_offset(&self, offset: Vector2) -> Vector2 {
+        Vector2::new(
+            offset.x.max(0.).min(self.max_offset_x()),
+            offset.y.max(0.).min(self.max_offset_y()),
+        )
+    }
+
+    /// Computes the closest offset for which `rect`, in content coordinates, is fully visible.
+    ///
+    /// When `rect` is bigger than the viewport, its top-left corner is favored.
+    pub fn offset_showing(&self, rect: Rectangle) -> Vector2 {
+        let visible = |offset: f32, viewport: f32, start: f32, length: f32| {
+            if start < offset || length > viewport {
+                start
+            } else if start + length > offset + viewport {
+                start + length - viewport
+            } else {
+                offset
+            }
+        };
+        self.clamp_offset(Vector2::new(
+            visible(
+                self.offset_x,
+                self.viewport_size.width,
+                rect.origin.x,
+                rect.size.width,
+            ),
+            visible(
+                self.offset_y,
+                self.viewport_size.height,
+                rect.origin.y,
+                rect.size.height,
+            ),
+        ))
+    }
 }
 
 pub struct Scrollable<T, B: Backend> {
@@ -21,6 +97,7 @@ pub struct Scrollable<T, B: Backend> {
     pub scrollbar_vertical: Option<WidgetPod<ScrollableState, B>>,
     pub scrollbar_horizontal: Option<WidgetPod<ScrollableState, B>>,
     pub state: ScrollableState,
+    motion: ScrollMotion,
 }
 
 impl<T, B: Backend> Scrollable<T, B> {
@@ -30,11 +107,50 @@ impl<T, B: Backend> Scrollable<T, B> {
             scrollbar_vertical: None,
             scrollbar_horizontal: None,
             state: ScrollableState::default(),
+            motion: ScrollMotion::default(),
         }
     }
+
+    /// Animates programmatic scrolls over `duration`, following `easing`
+    pub fn transition<EF: EasingFunction + 'static>(
+        mut self,
+        duration: Duration,
+        easing: EF,
+    ) -> Self {
+        self.motion.set_transition(duration, easing);
+        self
+    }
+
+    /// Enables momentum scrolling
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.motion.set_kinetic(kinetic);
+        self
+    }
+
+    /// Stops any scrolling motion in progress
+    pub fn stop(&mut self) {
+        self.motion.stop()
+    }
+
+    /// Scrolls to an absolute offset of the content
+    pub fn scroll_to(&mut self, offset: Vector2) {
+        self.motion.scroll_to(&mut self.state, offset)
+    }
+
+    /// Scrolls as little as possible for `rect`, in content coordinates, to be fully visible
+    pub fn scroll_to_rect(&mut self, rect: Rectangle) {
+        self.motion.scroll_to_rect(&mut self.state, rect)
+    }
 }
 
-impl<T, B: Backend> Widget<T> for Scrollable<T, B> {
+impl<T, B: Backend> Widget<T> for Scrollable<T, B>
+where
+    B::Event: Clone
+        + TransformEvent<AnimateEvent>
+        + TransformEvent<DragEvent>
+        + TransformEvent<MouseWheelEvent>,
+    B::EventReaction: TransformReaction<ScrollReaction>,
+{
     type Primitive = (ClipPrimitive<B::Primitive>, CommonPrimitive<B::Primitive>);
     type Context = B;
     type Event = B::Event;
@@ -53,10 +169,73 @@ impl<T, B: Backend> Widget<T> for Scrollable<T, B> {
         )
     }
 
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        self.motion.event(&mut self.state, origin, size, &event);
+
+        let content_origin = origin - self.state.offset();
+        let reaction =
+            TypedWidget::<T, B>::event(&mut self.child, content_origin, size, data, event)?;
+        let (scroll_reaction, reaction) = reaction.transform_reaction();
+        if let Some(scroll_reaction) = scroll_reaction {
+            self.motion
+                .handle_reaction(&mut self.state, scroll_reaction, origin);
+        }
+        reaction
+    }
+
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
-        let child_size = TypedWidget::<T, B>::layout(&mut self.child, bc, context, data);
-        self.state.child_size = child_size.clone();
-        child_size
+        // The child can grow past our bounds along the scrolled axes
+        let mut child_bc = bc.loosen();
+        if let ScrollType::Scroll = self.state.scroll_x {
+            child_bc = child_bc.unbound_x();
+        }
+        if let ScrollType::Scroll = self.state.scroll_y {
+            child_bc = child_bc.unbound_y();
+        }
+
+        let child_size = TypedWidget::<T, B>::layout(&mut self.child, &child_bc, context, data);
+        let viewport_size = bc.constrain(child_size);
+        self.state.child_size = child_size;
+        self.state.viewport_size = viewport_size;
+
+        // Overscrolled content is brought back by the kinetic motion instead
+        if !self.motion.allows_overscroll() {
+            self.state.clamp_offsets();
+        }
+        viewport_size
+    }
+
+    // Along a scrolled axis, the content can be shrunk down to nothing
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.state.scroll_x {
+            ScrollType::Scroll => 0.,
+            ScrollType::Hidden => {
+                TypedWidget::<T, B>::min_intrinsic_width(&mut self.child, height, context, data)
+            }
+        }
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.child, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        match self.state.scroll_y {
+            ScrollType::Scroll => 0.,
+            ScrollType::Hidden => {
+                TypedWidget::<T, B>::min_intrinsic_height(&mut self.child, width, context, data)
+            }
+        }
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.child, width, context, data)
     }
 }
 
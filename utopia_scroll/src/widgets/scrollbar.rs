@@ -0,0 +1,274 @@
+use utopia_core::{
+    component::Component,
+    contexts::ContextProvider,
+    controllers::{drag::DragEvent, TransformEvent},
+    math::{Size, Vector2},
+    widgets::{pod::WidgetPod, Widget},
+    Backend, BoxConstraints,
+};
+use utopia_decorations::primitives::quad::QuadPrimitive;
+use utopia_layout::spacer::Axis;
+
+use super::scrollable::ScrollableState;
+
+/// A scrollbar driven by a `ScrollableState`.
+///
+/// The thumb is sized by the ratio between the viewport and the content.
+/// Clicking the track scrolls by one page, dragging the thumb scrolls proportionally.
+pub struct Scrollbar<Color> {
+    axis: Axis,
+    thickness: f32,
+    min_thumb_length: f32,
+    track_color: Color,
+    thumb_color: Color,
+    /// Distance between the pointer and the start of the thumb while dragging
+    drag_anchor: Option<f32>,
+}
+
+impl<Color: Default> Scrollbar<Color> {
+    pub fn new(axis: Axis) -> Self {
+        Scrollbar {
+            axis,
+            thickness: 10.,
+            min_thumb_length: 10.,
+            track_color: Color::default(),
+            thumb_color: Color::default(),
+            drag_anchor: None,
+        }
+    }
+
+    pub fn vertical() -> Self {
+        Scrollbar::new(Axis::Vertical)
+    }
+
+    pub fn horizontal() -> Self {
+        Scrollbar::new(Axis::Horizontal)
+    }
+}
+
+impl<Color> Scrollbar<Color> {
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    pub fn min_thumb_length(mut self, min_thumb_length: f32) -> Self {
+        self.min_thumb_length = min_thumb_length;
+        self
+    }
+
+    pub fn track_color(mut self, color: Color) -> Self {
+        self.track_color = color;
+        self
+    }
+
+    pub fn thumb_color(mut self, color: Color) -> Self {
+        self.thumb_color = color;
+        self
+    }
+
+    fn main_axis(&self, vector: Vector2) -> f32 {
+        match self.axis {
+            Axis::Vertical => vector.y,
+            Axis::Horizontal => vector.x,
+        }
+    }
+
+    fn track_length(&self, size: Size) -> f32 {
+        match self.axis {
+            Axis::Vertical => size.height,
+            Axis::Horizontal => size.width,
+        }
+    }
+
+    fn offset(&self, state: &ScrollableState) -> f32 {
+        match self.axis {
+            Axis::Vertical => state.offset_y,
+            Axis::Horizontal => state.offset_x,
+        }
+    }
+
+    fn max_offset(&self, state: &ScrollableState) -> f32 {
+        match self.axis {
+            Axis::Vertical => state.max_offset_y(),
+            Axis::Horizontal => state.max_offset_x(),
+        }
+    }
+
+    fn set_offset(&self, state: &mut ScrollableState, offset: f32) {
+        match self.axis {
+            Axis::Vertical => state.offset_y = offset,
+            Axis::Horizontal => state.offset_x = offset,
+        }
+        state.clamp_offsets();
+    }
+
+    /// Returns the start and the length of the thumb along the track
+    fn thumb(&self, state: &ScrollableState, track_length: f32) -> (f32, f32) {
+        let (viewport, content) = match self.axis {
+            Axis::Vertical => (state.viewport_size.height, state.child_size.height),
+            Axis::Horizontal => (state.viewport_size.width, state.child_size.width),
+        };
+        let ratio = match content > 0. {
+            true => (viewport / content).min(1.),
+            false => 1.,
+        };
+        let length = (track_length * ratio)
+            .max(self.min_thumb_length)
+            .min(track_length);
+
+        let max_offset = self.max_offset(state);
+        // The offset can leave the scrollable range while overscrolling
+        let start = match max_offset > 0. {
+            true => (self.offset(state) / max_offset).clamp(0., 1.) * (track_length - length),
+            false => 0.,
+        };
+        (start, length)
+    }
+}
+
+impl<Color: Clone> Widget<ScrollableState> for Scrollbar<Color> {
+    type Primitive = (QuadPrimitive<Color>, QuadPrimitive<Color>);
+    type Context = ();
+    type Event = DragEvent;
+    type Reaction = ();
+
+    fn layout(
+        &mut self,
+        bc: &BoxConstraints,
+        _context: &Self::Context,
+        data: &ScrollableState,
+    ) -> Size {
+        let viewport = data.viewport_size;
+        let size = match self.axis {
+            Axis::Vertical => Size::new(
+                self.thickness,
+                if bc.is_height_bounded() {
+                    bc.max.height
+                } else {
+                    viewport.height
+                },
+            ),
+            Axis::Horizontal => Size::new(
+                if bc.is_width_bounded() {
+                    bc.max.width
+                } else {
+                    viewport.width
+                },
+                self.thickness,
+            ),
+        };
+        bc.constrain(size)
+    }
+
+    fn min_intrinsic_width(&mut self, _height: f32, _context: &(), _data: &ScrollableState) -> f32 {
+        match self.axis {
+            Axis::Vertical => self.thickness,
+            Axis::Horizontal => self.min_thumb_length,
+        }
+    }
+
+    fn max_intrinsic_width(&mut self, _height: f32, _context: &(), data: &ScrollableState) -> f32 {
+        match self.axis {
+            Axis::Vertical => self.thickness,
+            Axis::Horizontal => data.viewport_size.width.max(self.min_thumb_length),
+        }
+    }
+
+    fn min_intrinsic_height(&mut self, _width: f32, _context: &(), _data: &ScrollableState) -> f32 {
+        match self.axis {
+            Axis::Vertical => self.min_thumb_length,
+            Axis::Horizontal => self.thickness,
+        }
+    }
+
+    fn max_intrinsic_height(&mut self, _width: f32, _context: &(), data: &ScrollableState) -> f32 {
+        match self.axis {
+            Axis::Vertical => data.viewport_size.height.max(self.min_thumb_length),
+            Axis::Horizontal => self.thickness,
+        }
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &ScrollableState) -> Self::Primitive {
+        let (start, length) = self.thumb(data, self.track_length(size));
+        let (thumb_origin, thumb_size) = match self.axis {
+            Axis::Vertical => (
+                Vector2::new(origin.x, origin.y + start),
+                Size::new(size.width, length),
+            ),
+            Axis::Horizontal => (
+                Vector2::new(origin.x + start, origin.y),
+                Size::new(length, size.height),
+            ),
+        };
+
+        let track = QuadPrimitive {
+            color: self.track_color.clone(),
+            border_radius: 0,
+            origin,
+            size,
+        };
+        let thumb = QuadPrimitive {
+            color: self.thumb_color.clone(),
+            border_radius: 0,
+            origin: thumb_origin,
+            size: thumb_size,
+        };
+        (track, thumb)
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut ScrollableState,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        let target = event.pos() - origin;
+        let track_length = self.track_length(size);
+        let (start, length) = self.thumb(data, track_length);
+        let position = self.main_axis(target);
+
+        match event {
+            DragEvent::Pressed(_) => {
+                if !size.contains(target) {
+                    return None;
+                }
+                if position >= start && position <= start + length {
+                    self.drag_anchor = Some(position - start);
+                } else {
+                    let page = self.track_length(data.viewport_size);
+                    let offset = match position < start {
+                        true => self.offset(data) - page,
+                        false => self.offset(data) + page,
+                    };
+                    self.set_offset(data, offset);
+                }
+                Some(())
+            }
+            DragEvent::Moved(_) => {
+                let anchor = self.drag_anchor?;
+                let free_length = track_length - length;
+                if free_length > 0. {
+                    let ratio = (position - anchor) / free_length;
+                    let offset = ratio * self.max_offset(data);
+                    self.set_offset(data, offset);
+                }
+                Some(())
+            }
+            DragEvent::Released(_) => self.drag_anchor.take().map(|_| ()),
+        }
+    }
+}
+
+impl<Color: Clone + 'static, B: Backend + ContextProvider<()>> Component<ScrollableState, B>
+    for Scrollbar<Color>
+where
+    B::Primitive: From<(QuadPrimitive<Color>, QuadPrimitive<Color>)>,
+    B::Event: TransformEvent<DragEvent>,
+    B::EventReaction: From<()>,
+{
+    fn component(self) -> WidgetPod<ScrollableState, B> {
+        WidgetPod::new(self)
+    }
+}
@@ -1,27 +1,29 @@
+use std::time::Duration;
+
+use utopia_animations::EasingFunction;
 use utopia_core::{
-    math::{Size, Vector2},
+    math::{Rectangle, Size, Vector2},
     widgets::{pod::WidgetPod, TypedWidget, Widget},
     Backend, BoxConstraints, CommonPrimitive,
 };
 
+use crate::kinetic::Kinetic;
+
 use super::scrollable::{Scrollable, ScrollableState};
 
 pub struct ScrollView<T, B: Backend> {
     scroll: Scrollable<T, B>,
-    scrollable_state: ScrollableState,
     vertical: Option<WidgetPod<ScrollableState, B>>,
     horizontal: Option<WidgetPod<ScrollableState, B>>,
 }
 
 impl<T, B: Backend> ScrollView<T, B> {
     pub fn new<TW: TypedWidget<T, B> + 'static>(child: TW) -> Self {
-        let scrollable_state = ScrollableState::default();
         let scroll = Scrollable::new(child);
         ScrollView {
             scroll,
             vertical: None,
             horizontal: None,
-            scrollable_state: scrollable_state,
         }
     }
 
@@ -34,6 +36,36 @@ impl<T, B: Backend> ScrollView<T, B> {
         self.vertical = Some(vertical);
         self
     }
+
+    /// Animates programmatic scrolls over `duration`, following `easing`
+    pub fn transition<EF: EasingFunction + 'static>(
+        mut self,
+        duration: Duration,
+        easing: EF,
+    ) -> Self {
+        self.scroll = self.scroll.transition(duration, easing);
+        self
+    }
+
+    /// Enables momentum scrolling
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.scroll = self.scroll.kinetic(kinetic);
+        self
+    }
+
+    pub fn state(&self) -> &ScrollableState {
+        &self.scroll.state
+    }
+
+    /// Scrolls to an absolute offset of the content
+    pub fn scroll_to(&mut self, offset: Vector2) {
+        self.scroll.scroll_to(offset)
+    }
+
+    /// Scrolls as little as possible for `rect`, in content coordinates, to be fully visible
+    pub fn scroll_to_rect(&mut self, rect: Rectangle) {
+        self.scroll.scroll_to_rect(rect)
+    }
 }
 
 impl<T, B: Backend> Widget<T> for ScrollView<T, B>
@@ -49,16 +81,19 @@ where
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
         let size = TypedWidget::<T, B>::layout(&mut self.scroll, bc, context, data);
-        self.scroll.state = self.scrollable_state.clone();
-        let scrollable_state = &self.scrollable_state;
+
+        // Scrollbars are laid out against the visible area
+        let bc = BoxConstraints {
+            min: Size::ZERO,
+            max: size,
+        };
+        let scrollable_state = &self.scroll.state;
         if let Some(vertical) = self.vertical.as_mut() {
-            let bc = bc.loosen();
             let bar_size =
                 TypedWidget::<ScrollableState, B>::layout(vertical, &bc, context, scrollable_state);
             vertical.set_origin(Vector2::new(size.width - bar_size.width, 0.));
         }
         if let Some(horizontal) = self.horizontal.as_mut() {
-            let bc = bc.loosen();
             let bar_size = TypedWidget::<ScrollableState, B>::layout(
                 horizontal,
                 &bc,
@@ -74,19 +109,30 @@ where
         }
     }
 
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_width(&mut self.scroll, height, context, data)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_width(&mut self.scroll, height, context, data)
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::min_intrinsic_height(&mut self.scroll, width, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        TypedWidget::<T, B>::max_intrinsic_height(&mut self.scroll, width, context, data)
+    }
+
     fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
         let scroll = TypedWidget::<T, B>::draw(&self.scroll, origin, size, data);
         let vertical = self
             .vertical
             .as_ref()
             .map(|vertical| {
-                TypedWidget::<ScrollableState, B>::draw(
-                    vertical,
-                    origin,
-                    size,
-                    &self.scrollable_state,
-                )
-                .into()
+                TypedWidget::<ScrollableState, B>::draw(vertical, origin, size, &self.scroll.state)
+                    .into()
             })
             .unwrap_or_else(|| CommonPrimitive::<B::Primitive>::None);
         let horizontal = self
@@ -97,7 +143,7 @@ where
                     horizontal,
                     origin,
                     size,
-                    &self.scrollable_state,
+                    &self.scroll.state,
                 )
                 .into()
             })
@@ -119,9 +165,10 @@ where
                 horizontal,
                 origin,
                 size,
-                &mut self.scrollable_state,
+                &mut self.scroll.state,
                 event.clone(),
             ) {
+                self.scroll.stop();
                 return Some(reaction);
             }
         }
@@ -131,9 +178,10 @@ where
                 vertical,
                 origin,
                 size,
-                &mut self.scrollable_state,
+                &mut self.scroll.state,
                 event.clone(),
             ) {
+                self.scroll.stop();
                 return Some(reaction);
             }
         }
@@ -0,0 +1,335 @@
+use std::time::Duration;
+
+use utopia_animations::{event::AnimateEvent, EasingFunction};
+use utopia_core::{
+    controllers::{drag::DragEvent, wheel::MouseWheelEvent, TransformEvent},
+    math::{Size, Vector2},
+    reactions::TransformReaction,
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+
+use crate::{
+    kinetic::Kinetic, motion::ScrollMotion, primitive::ClipPrimitive, reaction::ScrollReaction,
+};
+
+use super::scrollable::ScrollableState;
+
+/// A vertically scrolling list of sections, each made of a header and a body.
+///
+/// The header of the section at the top of the viewport sticks there until
+/// the next header pushes it out, and is drawn above the clipped content.
+pub struct StickyHeaders<T, B: Backend> {
+    sections: Vec<StickySection<T, B>>,
+    vertical: Option<WidgetPod<ScrollableState, B>>,
+    pub state: ScrollableState,
+    motion: ScrollMotion,
+}
+
+struct StickySection<T, B: Backend> {
+    header: WidgetPod<T, B>,
+    body: WidgetPod<T, B>,
+    /// Position of the section in the content
+    top: f32,
+    header_size: Size,
+    body_size: Size,
+}
+
+impl<T, B: Backend> StickySection<T, B> {
+    fn bottom(&self) -> f32 {
+        self.top + self.header_size.height + self.body_size.height
+    }
+}
+
+impl<T, B: Backend> Default for StickyHeaders<T, B> {
+    fn default() -> Self {
+        StickyHeaders {
+            sections: Vec::new(),
+            vertical: None,
+            state: ScrollableState::default(),
+            motion: ScrollMotion::default(),
+        }
+    }
+}
+
+impl<T, B: Backend> StickyHeaders<T, B> {
+    pub fn new() -> Self {
+        StickyHeaders::default()
+    }
+
+    pub fn section<TWH: TypedWidget<T, B> + 'static, TWB: TypedWidget<T, B> + 'static>(
+        mut self,
+        header: TWH,
+        body: TWB,
+    ) -> Self {
+        self.sections.push(StickySection {
+            header: WidgetPod::new(header),
+            body: WidgetPod::new(body),
+            top: 0.,
+            header_size: Size::default(),
+            body_size: Size::default(),
+        });
+        self
+    }
+
+    pub fn vertical(mut self, vertical: WidgetPod<ScrollableState, B>) -> Self {
+        self.vertical = Some(vertical);
+        self
+    }
+
+    /// Animates programmatic scrolls over `duration`, following `easing`
+    pub fn transition<EF: EasingFunction + 'static>(
+        mut self,
+        duration: Duration,
+        easing: EF,
+    ) -> Self {
+        self.motion.set_transition(duration, easing);
+        self
+    }
+
+    /// Enables momentum scrolling
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.motion.set_kinetic(kinetic);
+        self
+    }
+
+    /// Scrolls so that the section at `index` starts at the top of the viewport
+    pub fn scroll_to_section(&mut self, index: usize) {
+        if let Some(section) = self.sections.get(index) {
+            let offset = Vector2::new(self.state.offset_x, section.top);
+            self.motion.scroll_to(&mut self.state, offset)
+        }
+    }
+
+    /// Returns the index of the section whose header is stuck, and the position
+    /// of that header relative to the top of the viewport
+    fn stuck_header(&self) -> Option<(usize, f32)> {
+        let offset = self.state.offset_y;
+        let index = self
+            .sections
+            .iter()
+            .position(|section| section.top < offset && section.bottom() > offset)?;
+        let section = &self.sections[index];
+        // The header slides out with the end of its section
+        let top = (section.bottom() - offset - section.header_size.height).min(0.);
+        Some((index, top))
+    }
+}
+
+impl<T, B: Backend> Widget<T> for StickyHeaders<T, B>
+where
+    B::Primitive: From<CommonPrimitive<B::Primitive>> + From<ClipPrimitive<B::Primitive>>,
+    B::Event: Clone
+        + TransformEvent<AnimateEvent>
+        + TransformEvent<DragEvent>
+        + TransformEvent<MouseWheelEvent>,
+    B::EventReaction: TransformReaction<ScrollReaction>,
+{
+    type Primitive = CommonPrimitive<B::Primitive>;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        let child_bc = BoxConstraints {
+            min: Size::ZERO,
+            max: Size::new(bc.max.width, f32::INFINITY),
+        };
+
+        let mut top = 0.;
+        let mut width: f32 = 0.;
+        for section in self.sections.iter_mut() {
+            section.top = top;
+            section.header_size =
+                TypedWidget::<T, B>::layout(&mut section.header, &child_bc, context, data);
+            section.body_size =
+                TypedWidget::<T, B>::layout(&mut section.body, &child_bc, context, data);
+            top = section.bottom();
+            width = width
+                .max(section.header_size.width)
+                .max(section.body_size.width);
+        }
+
+        let child_size = Size::new(width, top);
+        let size = bc.constrain(child_size);
+        self.state.child_size = child_size;
+        self.state.viewport_size = size;
+        if !self.motion.allows_overscroll() {
+            self.state.clamp_offsets();
+        }
+
+        if let Some(vertical) = self.vertical.as_mut() {
+            let bar_bc = BoxConstraints {
+                min: Size::ZERO,
+                max: size,
+            };
+            let bar_size =
+                TypedWidget::<ScrollableState, B>::layout(vertical, &bar_bc, context, &self.state);
+            vertical.set_origin(Vector2::new(size.width - bar_size.width, 0.));
+        }
+
+        size
+    }
+
+    fn min_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.sections
+            .iter_mut()
+            .flat_map(|section| [&mut section.header, &mut section.body])
+            .map(|widget| {
+                TypedWidget::<T, B>::min_intrinsic_width(widget, f32::INFINITY, context, data)
+            })
+            .fold(0., f32::max)
+    }
+
+    fn max_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.sections
+            .iter_mut()
+            .flat_map(|section| [&mut section.header, &mut section.body])
+            .map(|widget| {
+                TypedWidget::<T, B>::max_intrinsic_width(widget, f32::INFINITY, context, data)
+            })
+            .fold(0., f32::max)
+    }
+
+    // Sections are scrolled, and can be shrunk down to nothing
+    fn min_intrinsic_height(&mut self, _width: f32, _context: &Self::Context, _data: &T) -> f32 {
+        0.
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.sections
+            .iter_mut()
+            .flat_map(|section| [&mut section.header, &mut section.body])
+            .map(|widget| TypedWidget::<T, B>::max_intrinsic_height(widget, width, context, data))
+            .sum()
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        let stuck = self.stuck_header();
+
+        let mut children = Vec::with_capacity(self.sections.len() * 2);
+        for (index, section) in self.sections.iter().enumerate() {
+            let section_origin = origin + Vector2::new(0., section.top);
+            // The stuck header is drawn above the content instead
+            if stuck.map(|(stuck, _)| stuck != index).unwrap_or(true) {
+                children.push(TypedWidget::<T, B>::draw(
+                    &section.header,
+                    section_origin,
+                    section.header_size,
+                    data,
+                ));
+            }
+            children.push(TypedWidget::<T, B>::draw(
+                &section.body,
+                section_origin + Vector2::new(0., section.header_size.height),
+                section.body_size,
+                data,
+            ));
+        }
+        let content = ClipPrimitive {
+            origin,
+            offset: self.state.offset(),
+            bounds: size,
+            primitive: Box::new(CommonPrimitive::Group { children }.into()),
+        };
+
+        let header = stuck
+            .map(|(index, top)| {
+                let section = &self.sections[index];
+                ClipPrimitive {
+                    origin,
+                    offset: Vector2::ZERO,
+                    bounds: size,
+                    primitive: Box::new(TypedWidget::<T, B>::draw(
+                        &section.header,
+                        origin + Vector2::new(0., top),
+                        section.header_size,
+                        data,
+                    )),
+                }
+                .into()
+            })
+            .unwrap_or_else(|| CommonPrimitive::<B::Primitive>::None.into());
+
+        let vertical = self
+            .vertical
+            .as_ref()
+            .map(|vertical| {
+                TypedWidget::<ScrollableState, B>::draw(vertical, origin, size, &self.state)
+            })
+            .unwrap_or_else(|| CommonPrimitive::<B::Primitive>::None.into());
+
+        CommonPrimitive::Group {
+            children: vec![content.into(), header, vertical],
+        }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        if let Some(vertical) = self.vertical.as_mut() {
+            if let Some(reaction) = TypedWidget::<ScrollableState, B>::event(
+                vertical,
+                origin,
+                size,
+                &mut self.state,
+                event.clone(),
+            ) {
+                self.motion.stop();
+                return Some(reaction);
+            }
+        }
+
+        self.motion.event(&mut self.state, origin, size, &event);
+
+        // The stuck header sits above the content
+        let stuck = self.stuck_header();
+        let mut reaction = stuck.and_then(|(index, top)| {
+            let section = &mut self.sections[index];
+            TypedWidget::<T, B>::event(
+                &mut section.header,
+                origin + Vector2::new(0., top),
+                section.header_size,
+                data,
+                event.clone(),
+            )
+        });
+
+        let content_origin = origin - self.state.offset();
+        for (index, section) in self.sections.iter_mut().enumerate() {
+            if reaction.is_some() {
+                break;
+            }
+            let section_origin = content_origin + Vector2::new(0., section.top);
+            if stuck.map(|(stuck, _)| stuck != index).unwrap_or(true) {
+                reaction = TypedWidget::<T, B>::event(
+                    &mut section.header,
+                    section_origin,
+                    section.header_size,
+                    data,
+                    event.clone(),
+                );
+            }
+            if reaction.is_none() {
+                reaction = TypedWidget::<T, B>::event(
+                    &mut section.body,
+                    section_origin + Vector2::new(0., section.header_size.height),
+                    section.body_size,
+                    data,
+                    event.clone(),
+                );
+            }
+        }
+
+        let (scroll_reaction, reaction) = reaction?.transform_reaction();
+        if let Some(scroll_reaction) = scroll_reaction {
+            self.motion
+                .handle_reaction(&mut self.state, scroll_reaction, origin);
+        }
+        reaction
+    }
+}
@@ -0,0 +1,423 @@
+use std::time::Duration;
+
+use utopia_animations::{event::AnimateEvent, EasingFunction};
+use utopia_core::{
+    controllers::{drag::DragEvent, wheel::MouseWheelEvent, TransformEvent},
+    math::{Rectangle, Size, Vector2},
+    reactions::{self, CommonReaction, MergeReaction, TransformReaction},
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+
+use crate::{
+    kinetic::Kinetic, motion::ScrollMotion, primitive::ClipPrimitive, reaction::ScrollReaction,
+};
+
+use super::scrollable::ScrollableState;
+
+/// A vertically scrolling list that only lays out and draws the rows intersecting its viewport.
+///
+/// Rows are built on demand from `builder`, then recycled for other items
+/// as they leave the viewport, so row widgets should only depend on their item.
+pub struct VirtualList<U, B: Backend> {
+    builder: Box<dyn Fn() -> WidgetPod<U, B>>,
+    row_height: RowHeight,
+    /// Rows currently intersecting the viewport
+    rows: Vec<VirtualRow<U, B>>,
+    /// Rows that left the viewport, ready to be reused
+    pool: Vec<WidgetPod<U, B>>,
+    /// Measured height of every item, when `row_height` is `RowHeight::Measured`
+    heights: Vec<Option<f32>>,
+    /// Top of every item followed by the bottom of the last one, when `row_height` is `RowHeight::Measured`
+    tops: Vec<f32>,
+    vertical: Option<WidgetPod<ScrollableState, B>>,
+    pub state: ScrollableState,
+    motion: ScrollMotion,
+}
+
+/// How the height of the rows is known
+#[derive(Debug, Clone, Copy)]
+pub enum RowHeight {
+    /// Every row has the same height
+    Fixed(f32),
+    /// Rows are measured once laid out. `estimate` is used for rows that haven't been yet
+    Measured { estimate: f32 },
+}
+
+struct VirtualRow<U, B: Backend> {
+    index: usize,
+    widget: WidgetPod<U, B>,
+    size: Size,
+}
+
+impl<U, B: Backend> VirtualList<U, B> {
+    fn new<TW: TypedWidget<U, B> + 'static, F: Fn() -> TW + 'static>(
+        builder: F,
+        row_height: RowHeight,
+    ) -> Self {
+        VirtualList {
+            builder: Box::new(move || WidgetPod::new(builder())),
+            row_height,
+            rows: Vec::new(),
+            pool: Vec::new(),
+            heights: Vec::new(),
+            tops: vec![0.],
+            vertical: None,
+            state: ScrollableState::default(),
+            motion: ScrollMotion::default(),
+        }
+    }
+
+    /// A list where every row is `row_height` pixels high
+    pub fn fixed<TW: TypedWidget<U, B> + 'static, F: Fn() -> TW + 'static>(
+        row_height: f32,
+        builder: F,
+    ) -> Self {
+        VirtualList::new(builder, RowHeight::Fixed(row_height))
+    }
+
+    /// A list where rows take their own height, `estimate` being used until they are measured
+    pub fn measured<TW: TypedWidget<U, B> + 'static, F: Fn() -> TW + 'static>(
+        estimate: f32,
+        builder: F,
+    ) -> Self {
+        VirtualList::new(builder, RowHeight::Measured { estimate })
+    }
+
+    pub fn vertical(mut self, vertical: WidgetPod<ScrollableState, B>) -> Self {
+        self.vertical = Some(vertical);
+        self
+    }
+
+    /// Animates programmatic scrolls over `duration`, following `easing`
+    pub fn transition<EF: EasingFunction + 'static>(
+        mut self,
+        duration: Duration,
+        easing: EF,
+    ) -> Self {
+        self.motion.set_transition(duration, easing);
+        self
+    }
+
+    /// Enables momentum scrolling
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.motion.set_kinetic(kinetic);
+        self
+    }
+
+    /// Scrolls so that the item at `index` is fully visible.
+    ///
+    /// Outside of an event, the rows are updated once laid out again, see `Interface::request_layout`
+    pub fn scroll_to_index(&mut self, index: usize) {
+        let top = self.row_top(index);
+        let height = self.row_height(index);
+        let rect = Rectangle {
+            origin: Vector2::new(0., top),
+            size: Size::new(0., height),
+        };
+        self.motion.scroll_to_rect(&mut self.state, rect);
+    }
+
+    fn row_height(&self, index: usize) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured { estimate } => self
+                .heights
+                .get(index)
+                .copied()
+                .flatten()
+                .unwrap_or(estimate),
+        }
+    }
+
+    fn row_top(&self, index: usize) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(height) => height * index as f32,
+            RowHeight::Measured { estimate } => match self.tops.get(index) {
+                Some(top) => *top,
+                // Items added since the last layout
+                None => {
+                    let last = self.tops.len() - 1;
+                    self.tops[last] + estimate * (index - last) as f32
+                }
+            },
+        }
+    }
+
+    /// Recomputes the tops of the items following the one at `from`, after its height changed
+    fn update_tops(&mut self, from: usize) {
+        self.tops.truncate(from + 1);
+        for index in from..self.heights.len() {
+            let bottom = self.tops[index] + self.row_height(index);
+            self.tops.push(bottom);
+        }
+    }
+
+    /// Returns the range of items intersecting the viewport, and the top of the first one
+    fn visible_range(&self, len: usize) -> (std::ops::Range<usize>, f32) {
+        let start = self.state.offset_y;
+        let end = start + self.state.viewport_size.height;
+        match self.row_height {
+            RowHeight::Fixed(height) if height > 0. => {
+                let first = ((start / height).floor().max(0.) as usize).min(len);
+                let last = ((end / height).ceil().max(0.) as usize).min(len);
+                (first..last, first as f32 * height)
+            }
+            // Rows without height are never visible
+            RowHeight::Fixed(_) => (0..0, 0.),
+            RowHeight::Measured { .. } => {
+                let first = self.tops[1..=len].partition_point(|bottom| *bottom <= start);
+                let last = self.tops[..len].partition_point(|top| *top < end);
+                (first..last.max(first), self.tops[first])
+            }
+        }
+    }
+
+    /// Moves rows that left `range` to the pool, and builds the missing ones
+    fn recycle(&mut self, range: std::ops::Range<usize>) {
+        let (kept, gone): (Vec<_>, Vec<_>) = self
+            .rows
+            .drain(..)
+            .partition(|row| range.contains(&row.index));
+        self.pool.extend(gone.into_iter().map(|row| row.widget));
+
+        let mut kept = kept.into_iter().peekable();
+        let mut rows = Vec::with_capacity(range.len());
+        for index in range {
+            match kept.peek() {
+                Some(row) if row.index == index => rows.push(kept.next().unwrap()),
+                _ => {
+                    let mut widget = self.pool.pop().unwrap_or_else(|| (self.builder)());
+                    // A recycled row shows another item, its cached layout is stale
+                    widget.request_layout();
+                    rows.push(VirtualRow {
+                        index,
+                        widget,
+                        size: Size::ZERO,
+                    })
+                }
+            }
+        }
+        self.rows = rows;
+    }
+}
+
+impl<U, B: Backend> Widget<Vec<U>> for VirtualList<U, B>
+where
+    B::Primitive: From<CommonPrimitive<B::Primitive>>,
+    B::Event: Clone
+        + TransformEvent<AnimateEvent>
+        + TransformEvent<DragEvent>
+        + TransformEvent<MouseWheelEvent>,
+    B::EventReaction: TransformReaction<ScrollReaction> + From<CommonReaction> + MergeReaction,
+{
+    type Primitive = (ClipPrimitive<B::Primitive>, CommonPrimitive<B::Primitive>);
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &Vec<U>) -> Size {
+        if let RowHeight::Measured { .. } = self.row_height {
+            let kept = self.heights.len().min(data.len());
+            self.heights.resize(data.len(), None);
+            self.update_tops(kept);
+        }
+
+        // Without an upper bound, every row is visible
+        let height = match bc.is_height_bounded() {
+            true => bc.max.height,
+            false => self.row_top(data.len()),
+        };
+        self.state.viewport_size = Size::new(bc.max.width, height);
+        // The list may have shrunk since the last layout
+        self.state.child_size = Size::new(bc.max.width, self.row_top(data.len()));
+        if !self.motion.allows_overscroll() {
+            self.state.clamp_offsets();
+        }
+
+        let (range, mut top) = self.visible_range(data.len());
+        self.recycle(range);
+
+        let mut width: f32 = 0.;
+        let mut resized = None;
+        for row in self.rows.iter_mut() {
+            let row_bc = match self.row_height {
+                RowHeight::Fixed(height) => BoxConstraints {
+                    min: Size::new(0., height),
+                    max: Size::new(bc.max.width, height),
+                },
+                RowHeight::Measured { .. } => BoxConstraints {
+                    min: Size::ZERO,
+                    max: Size::new(bc.max.width, f32::INFINITY),
+                },
+            };
+            row.size =
+                TypedWidget::<U, B>::layout(&mut row.widget, &row_bc, context, &data[row.index]);
+            row.widget.set_origin(Vector2::new(0., top));
+            top += row.size.height;
+            width = width.max(row.size.width);
+            if let RowHeight::Measured { .. } = self.row_height {
+                if self.heights[row.index] != Some(row.size.height) {
+                    resized.get_or_insert(row.index);
+                }
+                self.heights[row.index] = Some(row.size.height);
+            }
+        }
+        if let Some(from) = resized {
+            self.update_tops(from);
+        }
+
+        let content_height = self.row_top(data.len());
+        let size = bc.constrain(Size::new(width, height));
+        self.state.child_size = Size::new(width.max(size.width), content_height);
+        self.state.viewport_size = size;
+        if !self.motion.allows_overscroll() {
+            self.state.clamp_offsets();
+        }
+
+        if let Some(vertical) = self.vertical.as_mut() {
+            let bar_bc = BoxConstraints {
+                min: Size::ZERO,
+                max: size,
+            };
+            let bar_size =
+                TypedWidget::<ScrollableState, B>::layout(vertical, &bar_bc, context, &self.state);
+            vertical.set_origin(Vector2::new(size.width - bar_size.width, 0.));
+        }
+
+        size
+    }
+
+    // Only the rows built by the last layout are measured, building every row would defeat the list
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &Vec<U>) -> f32 {
+        self.rows
+            .iter_mut()
+            .map(|row| {
+                TypedWidget::<U, B>::min_intrinsic_width(
+                    &mut row.widget,
+                    height,
+                    context,
+                    &data[row.index],
+                )
+            })
+            .fold(0., f32::max)
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &Vec<U>) -> f32 {
+        self.rows
+            .iter_mut()
+            .map(|row| {
+                TypedWidget::<U, B>::max_intrinsic_width(
+                    &mut row.widget,
+                    height,
+                    context,
+                    &data[row.index],
+                )
+            })
+            .fold(0., f32::max)
+    }
+
+    // The list scrolls, so it can be shrunk down to nothing
+    fn min_intrinsic_height(
+        &mut self,
+        _width: f32,
+        _context: &Self::Context,
+        _data: &Vec<U>,
+    ) -> f32 {
+        0.
+    }
+
+    fn max_intrinsic_height(
+        &mut self,
+        _width: f32,
+        _context: &Self::Context,
+        data: &Vec<U>,
+    ) -> f32 {
+        self.row_top(data.len())
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &Vec<U>) -> Self::Primitive {
+        // The list may have scrolled since the last layout
+        let (start, end) = (self.state.offset_y, self.state.offset_y + size.height);
+        let children = self
+            .rows
+            .iter()
+            .filter(|row| {
+                let top = row.widget.origin().y;
+                top < end && top + row.size.height > start
+            })
+            .map(|row| TypedWidget::<U, B>::draw(&row.widget, origin, row.size, &data[row.index]))
+            .collect();
+        let vertical = self
+            .vertical
+            .as_ref()
+            .map(|vertical| {
+                TypedWidget::<ScrollableState, B>::draw(vertical, origin, size, &self.state).into()
+            })
+            .unwrap_or_else(|| CommonPrimitive::<B::Primitive>::None);
+
+        (
+            ClipPrimitive {
+                origin,
+                offset: self.state.offset(),
+                bounds: size,
+                primitive: Box::new(CommonPrimitive::Group { children }.into()),
+            },
+            vertical,
+        )
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut Vec<U>,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        let (offset_x, offset_y) = (self.state.offset_x, self.state.offset_y);
+        let state = &mut self.state;
+        let scrollbar_reaction = self.vertical.as_mut().and_then(|vertical| {
+            TypedWidget::<ScrollableState, B>::event(vertical, origin, size, state, event.clone())
+        });
+
+        let reaction = match scrollbar_reaction {
+            Some(reaction) => {
+                self.motion.stop();
+                Some(reaction)
+            }
+            None => {
+                self.motion.event(&mut self.state, origin, size, &event);
+
+                let content_origin = origin - self.state.offset();
+                let reaction = self.rows.iter_mut().find_map(|row| {
+                    TypedWidget::<U, B>::event(
+                        &mut row.widget,
+                        content_origin,
+                        row.size,
+                        &mut data[row.index],
+                        event.clone(),
+                    )
+                });
+                let (scroll_reaction, reaction) = match reaction {
+                    Some(reaction) => {
+                        TransformReaction::<ScrollReaction>::transform_reaction(reaction)
+                    }
+                    None => (None, None),
+                };
+                if let Some(scroll_reaction) = scroll_reaction {
+                    self.motion
+                        .handle_reaction(&mut self.state, scroll_reaction, origin);
+                }
+                reaction
+            }
+        };
+
+        // Other rows become visible when the list scrolls
+        let scrolled = self.state.offset_x != offset_x || self.state.offset_y != offset_y;
+        let layout_reaction = match scrolled {
+            true => Some(CommonReaction::ReLayoutSelf.into()),
+            false => None,
+        };
+        reactions::merge(reaction, layout_reaction)
+    }
+}
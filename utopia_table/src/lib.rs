@@ -0,0 +1,3 @@
+//! Utopia's Table widget
+
+pub mod widgets;
@@ -0,0 +1,486 @@
+use std::cmp::Ordering;
+
+use utopia_core::{
+    controllers::{
+        click::{MouseButton, MouseClickEvent},
+        TransformEvent,
+    },
+    math::{Size, Vector2},
+    reactions::CommonReaction,
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+use utopia_decorations::primitives::quad::QuadPrimitive;
+
+type Compare<R> = Box<dyn Fn(&R, &R) -> Ordering>;
+type OnSelect<R> = Box<dyn Fn(&mut Vec<R>, usize)>;
+
+/// A Widget displaying a `Vec` of rows, one column per field.
+///
+/// Clicking a sortable header sorts the rows, clicking a row selects it.
+pub struct Table<R, Color, B: Backend> {
+    columns: Vec<TableColumn<R, B>>,
+    /// Cells of every row, in the order of `columns`
+    rows: Vec<Vec<WidgetPod<R, B>>>,
+    widths: Vec<f32>,
+    header_height: f32,
+    row_heights: Vec<f32>,
+    selected: Option<usize>,
+    sort: Option<(usize, SortOrder)>,
+    selection_color: Color,
+    on_select: Option<OnSelect<R>>,
+}
+
+/// A column of a `Table`
+pub struct TableColumn<R, B: Backend> {
+    header: WidgetPod<Vec<R>, B>,
+    cell: Box<dyn Fn() -> WidgetPod<R, B>>,
+    width: ColumnWidth,
+    compare: Option<Compare<R>>,
+}
+
+/// How the width of a column is computed
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColumnWidth {
+    Fixed(f32),
+    /// Fits the widest cell, header included
+    #[default]
+    Auto,
+    /// Share of the width left by the other columns. Behaves like `Auto` when the table is unbounded
+    Flex(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl<R, B: Backend> TableColumn<R, B> {
+    /// A column showing `header` above cells built by `cell`
+    pub fn new<TWH, TWC, F>(header: TWH, cell: F) -> Self
+    where
+        TWH: TypedWidget<Vec<R>, B> + 'static,
+        TWC: TypedWidget<R, B> + 'static,
+        F: Fn() -> TWC + 'static,
+    {
+        TableColumn {
+            header: WidgetPod::new(header),
+            cell: Box::new(move || WidgetPod::new(cell())),
+            width: ColumnWidth::default(),
+            compare: None,
+        }
+    }
+
+    pub fn width(mut self, width: ColumnWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sorts the rows following `compare` when the header is clicked
+    pub fn sortable<F: Fn(&R, &R) -> Ordering + 'static>(mut self, compare: F) -> Self {
+        self.compare = Some(Box::new(compare));
+        self
+    }
+}
+
+impl<R, Color: Default, B: Backend> Default for Table<R, Color, B> {
+    fn default() -> Self {
+        Table {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            widths: Vec::new(),
+            header_height: 0.,
+            row_heights: Vec::new(),
+            selected: None,
+            sort: None,
+            selection_color: Color::default(),
+            on_select: None,
+        }
+    }
+}
+
+impl<R, Color: Default, B: Backend> Table<R, Color, B> {
+    pub fn new() -> Self {
+        Table::default()
+    }
+}
+
+impl<R, Color, B: Backend> Table<R, Color, B> {
+    pub fn column(mut self, column: TableColumn<R, B>) -> Self {
+        self.columns.push(column);
+        // Existing rows lack a cell for this column
+        self.rows.clear();
+        self
+    }
+
+    pub fn selection_color(mut self, color: Color) -> Self {
+        self.selection_color = color;
+        self
+    }
+
+    /// Calls `on_select` with the index of a row when it gets selected
+    pub fn on_select<F: Fn(&mut Vec<R>, usize) + 'static>(mut self, on_select: F) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn select(&mut self, selected: Option<usize>) {
+        self.selected = selected;
+    }
+
+    pub fn sort_order(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sorts `data` by the column at `index`, keeping the selected row selected.
+    ///
+    /// Outside of an event, the table is sorted on screen once laid out again, see `Interface::request_layout`
+    pub fn sort_by(&mut self, index: usize, order: SortOrder, data: &mut Vec<R>) {
+        let compare = match self.columns.get(index).and_then(|c| c.compare.as_ref()) {
+            Some(compare) => compare,
+            None => return,
+        };
+
+        let mut indices: Vec<usize> = (0..data.len()).collect();
+        indices.sort_by(|a, b| match order {
+            SortOrder::Ascending => compare(&data[*a], &data[*b]),
+            SortOrder::Descending => compare(&data[*b], &data[*a]),
+        });
+        self.selected = self
+            .selected
+            .and_then(|selected| indices.iter().position(|index| *index == selected));
+
+        let mut rows: Vec<Option<R>> = data.drain(..).map(Some).collect();
+        data.extend(indices.into_iter().filter_map(|index| rows[index].take()));
+        self.sort = Some((index, order));
+        // Cells now show other rows
+        for cell in self.rows.iter_mut().flatten() {
+            cell.request_layout();
+        }
+    }
+
+    /// Builds the cells of new rows, and drops the ones of removed rows
+    fn build_rows(&mut self, data: &[R]) {
+        let columns = &self.columns;
+        self.rows.truncate(data.len());
+        while self.rows.len() < data.len() {
+            self.rows
+                .push(columns.iter().map(|column| (column.cell)()).collect());
+        }
+    }
+
+    /// Min or max intrinsic width of the column at `index`, fitting its header and every cell
+    fn intrinsic_column_width(
+        &mut self,
+        index: usize,
+        min: bool,
+        context: &B,
+        data: &Vec<R>,
+    ) -> f32 {
+        let header = &mut self.columns[index].header;
+        let header = match min {
+            true => {
+                TypedWidget::<Vec<R>, B>::min_intrinsic_width(header, f32::INFINITY, context, data)
+            }
+            false => {
+                TypedWidget::<Vec<R>, B>::max_intrinsic_width(header, f32::INFINITY, context, data)
+            }
+        };
+        self.rows
+            .iter_mut()
+            .zip(data.iter())
+            .map(|(cells, row)| {
+                let cell = &mut cells[index];
+                match min {
+                    true => {
+                        TypedWidget::<R, B>::min_intrinsic_width(cell, f32::INFINITY, context, row)
+                    }
+                    false => {
+                        TypedWidget::<R, B>::max_intrinsic_width(cell, f32::INFINITY, context, row)
+                    }
+                }
+            })
+            .fold(header, f32::max)
+    }
+
+    /// Width of every column when the table is given `space`
+    fn column_widths(&mut self, space: f32, min: bool, context: &B, data: &Vec<R>) -> Vec<f32> {
+        let mut widths = Vec::with_capacity(self.columns.len());
+        for index in 0..self.columns.len() {
+            let width = match self.columns[index].width {
+                ColumnWidth::Fixed(width) => width,
+                ColumnWidth::Flex(_) if space.is_finite() => 0.,
+                ColumnWidth::Auto | ColumnWidth::Flex(_) => {
+                    self.intrinsic_column_width(index, min, context, data)
+                }
+            };
+            widths.push(width);
+        }
+        if space.is_finite() {
+            let flex_sum: f32 = self
+                .columns
+                .iter()
+                .filter_map(|column| match column.width {
+                    ColumnWidth::Flex(flex) => Some(flex),
+                    _ => None,
+                })
+                .sum();
+            if flex_sum > 0. {
+                let free_space = (space - widths.iter().sum::<f32>()).max(0.);
+                for (column, width) in self.columns.iter().zip(widths.iter_mut()) {
+                    if let ColumnWidth::Flex(flex) = column.width {
+                        *width = free_space * flex / flex_sum;
+                    }
+                }
+            }
+        }
+        widths
+    }
+
+    /// Min or max intrinsic height of the header and every row, given the width of the table
+    fn intrinsic_height(&mut self, width: f32, min: bool, context: &B, data: &Vec<R>) -> f32 {
+        let widths = self.column_widths(width, false, context, data);
+        let header_height = self
+            .columns
+            .iter_mut()
+            .zip(widths.iter())
+            .map(|(column, width)| {
+                let header = &mut column.header;
+                match min {
+                    true => TypedWidget::<Vec<R>, B>::min_intrinsic_height(
+                        header, *width, context, data,
+                    ),
+                    false => TypedWidget::<Vec<R>, B>::max_intrinsic_height(
+                        header, *width, context, data,
+                    ),
+                }
+            })
+            .fold(0., f32::max);
+        let rows_height: f32 = self
+            .rows
+            .iter_mut()
+            .zip(data.iter())
+            .map(|(cells, row)| {
+                cells
+                    .iter_mut()
+                    .zip(widths.iter())
+                    .map(|(cell, width)| match min {
+                        true => {
+                            TypedWidget::<R, B>::min_intrinsic_height(cell, *width, context, row)
+                        }
+                        false => {
+                            TypedWidget::<R, B>::max_intrinsic_height(cell, *width, context, row)
+                        }
+                    })
+                    .fold(0., f32::max)
+            })
+            .sum();
+        header_height + rows_height
+    }
+
+    /// Returns the start of every column, and the total width
+    fn column_positions(&self) -> (Vec<f32>, f32) {
+        let mut position = 0.;
+        let positions = self
+            .widths
+            .iter()
+            .map(|width| {
+                let start = position;
+                position += width;
+                start
+            })
+            .collect();
+        (positions, position)
+    }
+}
+
+impl<R, Color: Clone, B: Backend> Widget<Vec<R>> for Table<R, Color, B>
+where
+    B::Primitive: From<QuadPrimitive<Color>>,
+    B::Event: Clone + TransformEvent<MouseClickEvent>,
+    B::EventReaction: From<CommonReaction>,
+{
+    type Primitive = CommonPrimitive<B::Primitive>;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &Vec<R>) -> Size {
+        // Step 1 : Build the cells of new rows
+        self.build_rows(data);
+        if self.selected.map(|s| s >= data.len()).unwrap_or(false) {
+            self.selected = None;
+        }
+
+        // Step 2 : Compute column widths
+        let widths = self.column_widths(bc.max.width, false, context, data);
+        self.widths = widths;
+        let (positions, width) = self.column_positions();
+
+        // Step 3 : Layout headers and cells in their column
+        let cell_bc = |width: f32| BoxConstraints {
+            min: Size::new(width, 0.),
+            max: Size::new(width, f32::INFINITY),
+        };
+        self.header_height = 0.;
+        for ((column, width), x) in self
+            .columns
+            .iter_mut()
+            .zip(self.widths.iter())
+            .zip(positions.iter())
+        {
+            let size = TypedWidget::<Vec<R>, B>::layout(
+                &mut column.header,
+                &cell_bc(*width),
+                context,
+                data,
+            );
+            column.header.set_origin(Vector2::new(*x, 0.));
+            self.header_height = self.header_height.max(size.height);
+        }
+
+        self.row_heights.clear();
+        let mut y = self.header_height;
+        for (cells, row) in self.rows.iter_mut().zip(data.iter()) {
+            let mut height: f32 = 0.;
+            for ((cell, width), x) in cells
+                .iter_mut()
+                .zip(self.widths.iter())
+                .zip(positions.iter())
+            {
+                let size = TypedWidget::<R, B>::layout(cell, &cell_bc(*width), context, row);
+                cell.set_origin(Vector2::new(*x, y));
+                height = height.max(size.height);
+            }
+            self.row_heights.push(height);
+            y += height;
+        }
+
+        bc.constrain(Size::new(width, y))
+    }
+
+    fn min_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &Vec<R>) -> f32 {
+        self.build_rows(data);
+        self.column_widths(f32::INFINITY, true, context, data)
+            .iter()
+            .sum()
+    }
+
+    fn max_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &Vec<R>) -> f32 {
+        self.build_rows(data);
+        self.column_widths(f32::INFINITY, false, context, data)
+            .iter()
+            .sum()
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &Vec<R>) -> f32 {
+        self.build_rows(data);
+        self.intrinsic_height(width, true, context, data)
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &Vec<R>) -> f32 {
+        self.build_rows(data);
+        self.intrinsic_height(width, false, context, data)
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &Vec<R>) -> Self::Primitive {
+        let mut children = Vec::new();
+
+        // The selection is drawn below its row
+        if let Some(selected) = self.selected {
+            let y = self.header_height + self.row_heights[..selected].iter().sum::<f32>();
+            children.push(
+                QuadPrimitive {
+                    color: self.selection_color.clone(),
+                    border_radius: 0,
+                    origin: origin + Vector2::new(0., y),
+                    size: Size::new(size.width, self.row_heights[selected]),
+                }
+                .into(),
+            );
+        }
+
+        children.extend(
+            self.columns
+                .iter()
+                .map(|column| TypedWidget::<Vec<R>, B>::draw(&column.header, origin, size, data)),
+        );
+        for (cells, row) in self.rows.iter().zip(data.iter()) {
+            children.extend(
+                cells
+                    .iter()
+                    .map(|cell| TypedWidget::<R, B>::draw(cell, origin, size, row)),
+            );
+        }
+
+        CommonPrimitive::Group { children }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut Vec<R>,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        for column in self.columns.iter_mut() {
+            if let Some(reaction) = TypedWidget::<Vec<R>, B>::event(
+                &mut column.header,
+                origin,
+                size,
+                data,
+                event.clone(),
+            ) {
+                return Some(reaction);
+            }
+        }
+        for (cells, row) in self.rows.iter_mut().zip(data.iter_mut()) {
+            for cell in cells.iter_mut() {
+                if let Some(reaction) =
+                    TypedWidget::<R, B>::event(cell, origin, size, row, event.clone())
+                {
+                    return Some(reaction);
+                }
+            }
+        }
+
+        let click = match TransformEvent::<MouseClickEvent>::transform_event(event) {
+            Some(MouseClickEvent {
+                pos,
+                mouse_button: MouseButton::Left,
+            }) => pos - origin,
+            _ => return None,
+        };
+        if !size.contains(click) {
+            return None;
+        }
+
+        if click.y < self.header_height {
+            // Clicking the sorted column again reverses the order
+            let (positions, _) = self.column_positions();
+            let index = positions.iter().rposition(|x| *x <= click.x)?;
+            let order = match self.sort {
+                Some((sorted, SortOrder::Ascending)) if sorted == index => SortOrder::Descending,
+                _ => SortOrder::Ascending,
+            };
+            self.sort_by(index, order, data);
+            Some(CommonReaction::ReLayout.into())
+        } else {
+            let mut y = self.header_height;
+            let index = self.row_heights.iter().position(|height| {
+                y += height;
+                click.y < y
+            })?;
+            self.selected = Some(index);
+            if let Some(on_select) = self.on_select.as_ref() {
+                on_select(data, index);
+            }
+            Some(CommonReaction::ReLayout.into())
+        }
+    }
+}
@@ -0,0 +1,3 @@
+//! Utopia's Tabs widget
+
+pub mod widgets;
@@ -0,0 +1,285 @@
+use utopia_core::{
+    controllers::{
+        click::{MouseButton, MouseClickEvent},
+        TransformEvent,
+    },
+    lens::Lens,
+    math::{Insets, Size, Vector2},
+    reactions::CommonReaction,
+    widgets::{pod::WidgetPod, TypedWidget, Widget},
+    Backend, BoxConstraints, CommonPrimitive,
+};
+use utopia_decorations::primitives::quad::QuadPrimitive;
+
+type Builder<T, B> = Box<dyn Fn() -> WidgetPod<T, B>>;
+
+/// A Widget showing a bar of tabs above the content of the selected tab.
+///
+/// The index of the selected tab is read from and written to the data through a lens.
+/// Contents are built the first time their tab is selected, and keep their state
+/// while another tab is selected.
+pub struct Tabs<T, L, Color, B: Backend> {
+    tabs: Vec<Tab<T, B>>,
+    selected: L,
+    selected_color: Color,
+    /// Start of every label in the bar
+    positions: Vec<f32>,
+    bar_height: f32,
+    /// Tab shown by the last layout
+    shown: Option<usize>,
+}
+
+struct Tab<T, B: Backend> {
+    label: WidgetPod<T, B>,
+    label_size: Size,
+    builder: Builder<T, B>,
+    content: Option<WidgetPod<T, B>>,
+}
+
+impl<T, L: Lens<T, usize>, Color: Default, B: Backend> Tabs<T, L, Color, B> {
+    pub fn new(selected: L) -> Self {
+        Tabs {
+            tabs: Vec::new(),
+            selected,
+            selected_color: Color::default(),
+            positions: Vec::new(),
+            bar_height: 0.,
+            shown: None,
+        }
+    }
+}
+
+impl<T, L: Lens<T, usize>, Color, B: Backend> Tabs<T, L, Color, B> {
+    /// Adds a tab showing `label` in the bar, and the content built by `builder` when selected
+    pub fn tab<TWL, TWC, F>(mut self, label: TWL, builder: F) -> Self
+    where
+        TWL: TypedWidget<T, B> + 'static,
+        TWC: TypedWidget<T, B> + 'static,
+        F: Fn() -> TWC + 'static,
+    {
+        self.tabs.push(Tab {
+            label: WidgetPod::new(label),
+            label_size: Size::default(),
+            builder: Box::new(move || WidgetPod::new(builder())),
+            content: None,
+        });
+        self
+    }
+
+    /// Color drawn behind the label of the selected tab
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.selected_color = color;
+        self
+    }
+
+    /// Index of the tab to show, if any
+    fn selected(&self, data: &T) -> Option<usize> {
+        let selected = self.selected.with(data, |selected| *selected);
+        match self.tabs.is_empty() {
+            true => None,
+            false => Some(selected.min(self.tabs.len() - 1)),
+        }
+    }
+
+    /// Content of the tab at `index`, built if needed
+    fn content(&mut self, index: usize) -> &mut WidgetPod<T, B> {
+        let tab = &mut self.tabs[index];
+        let builder = &tab.builder;
+        tab.content.get_or_insert_with(builder)
+    }
+
+    /// Height of the bar, fitting the tallest label
+    fn intrinsic_bar_height(&mut self, context: &B, data: &T) -> f32 {
+        self.tabs
+            .iter_mut()
+            .map(|tab| {
+                TypedWidget::<T, B>::max_intrinsic_height(
+                    &mut tab.label,
+                    f32::INFINITY,
+                    context,
+                    data,
+                )
+            })
+            .fold(0., f32::max)
+    }
+
+    /// Intrinsic width of the bar or of the selected content, whichever is the widest
+    fn intrinsic_width<F>(&mut self, height: f32, context: &B, data: &T, mut width: F) -> f32
+    where
+        F: FnMut(&mut WidgetPod<T, B>, f32) -> f32,
+    {
+        let bar_width: f32 = self
+            .tabs
+            .iter_mut()
+            .map(|tab| width(&mut tab.label, f32::INFINITY))
+            .sum();
+        let content_height = (height - self.intrinsic_bar_height(context, data)).max(0.);
+        let content_width = match self.selected(data) {
+            Some(index) => width(self.content(index), content_height),
+            None => 0.,
+        };
+        bar_width.max(content_width)
+    }
+
+    /// Intrinsic height of the bar above the selected content
+    fn intrinsic_height<F>(&mut self, width: f32, context: &B, data: &T, mut height: F) -> f32
+    where
+        F: FnMut(&mut WidgetPod<T, B>, f32) -> f32,
+    {
+        let bar_height = self.intrinsic_bar_height(context, data);
+        let content_height = match self.selected(data) {
+            Some(index) => height(self.content(index), width),
+            None => 0.,
+        };
+        bar_height + content_height
+    }
+}
+
+impl<T, L: Lens<T, usize>, Color: Clone, B: Backend> Widget<T> for Tabs<T, L, Color, B>
+where
+    B::Primitive: From<QuadPrimitive<Color>>,
+    B::Event: Clone + TransformEvent<MouseClickEvent>,
+    B::EventReaction: From<CommonReaction>,
+{
+    type Primitive = CommonPrimitive<B::Primitive>;
+    type Context = B;
+    type Event = B::Event;
+    type Reaction = B::EventReaction;
+
+    fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
+        // Step 1 : Layout labels in a row
+        let loosened = bc.loosen().unbound_x();
+        let mut x = 0.;
+        self.positions.clear();
+        self.bar_height = 0.;
+        for tab in self.tabs.iter_mut() {
+            tab.label_size = TypedWidget::<T, B>::layout(&mut tab.label, &loosened, context, data);
+            tab.label.set_origin(Vector2::new(x, 0.));
+            self.positions.push(x);
+            x += tab.label_size.width;
+            self.bar_height = self.bar_height.max(tab.label_size.height);
+        }
+        let bar_width = x;
+
+        // Step 2 : Layout the content of the selected tab, below the bar
+        self.shown = self.selected(data);
+        let content_size = match self.shown {
+            Some(index) => {
+                let content_bc = bc.shrink(Insets {
+                    top: self.bar_height,
+                    ..Insets::ZERO
+                });
+                let bar_height = self.bar_height;
+                let content = self.content(index);
+                content.set_origin(Vector2::new(0., bar_height));
+                TypedWidget::<T, B>::layout(content, &content_bc, context, data)
+            }
+            None => Size::ZERO,
+        };
+
+        bc.constrain(Size::new(
+            bar_width.max(content_size.width),
+            self.bar_height + content_size.height,
+        ))
+    }
+
+    fn min_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic_width(height, context, data, |widget, height| {
+            TypedWidget::<T, B>::min_intrinsic_width(widget, height, context, data)
+        })
+    }
+
+    fn max_intrinsic_width(&mut self, height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic_width(height, context, data, |widget, height| {
+            TypedWidget::<T, B>::max_intrinsic_width(widget, height, context, data)
+        })
+    }
+
+    fn min_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic_height(width, context, data, |widget, width| {
+            TypedWidget::<T, B>::min_intrinsic_height(widget, width, context, data)
+        })
+    }
+
+    fn max_intrinsic_height(&mut self, width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.intrinsic_height(width, context, data, |widget, width| {
+            TypedWidget::<T, B>::max_intrinsic_height(widget, width, context, data)
+        })
+    }
+
+    fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
+        let mut children = Vec::new();
+
+        // The selection is drawn below its label
+        if let Some(index) = self.shown {
+            children.push(
+                QuadPrimitive {
+                    color: self.selected_color.clone(),
+                    border_radius: 0,
+                    origin: origin + Vector2::new(self.positions[index], 0.),
+                    size: Size::new(self.tabs[index].label_size.width, self.bar_height),
+                }
+                .into(),
+            );
+        }
+
+        children.extend(
+            self.tabs
+                .iter()
+                .map(|tab| TypedWidget::<T, B>::draw(&tab.label, origin, size, data)),
+        );
+        if let Some(content) = self
+            .shown
+            .and_then(|index| self.tabs[index].content.as_ref())
+        {
+            children.push(TypedWidget::<T, B>::draw(content, origin, size, data));
+        }
+
+        CommonPrimitive::Group { children }
+    }
+
+    fn event(
+        &mut self,
+        origin: Vector2,
+        size: Size,
+        data: &mut T,
+        event: Self::Event,
+    ) -> Option<Self::Reaction> {
+        for tab in self.tabs.iter_mut() {
+            if let Some(reaction) =
+                TypedWidget::<T, B>::event(&mut tab.label, origin, size, data, event.clone())
+            {
+                return Some(reaction);
+            }
+        }
+        if let Some(index) = self.shown {
+            if let Some(content) = self.tabs[index].content.as_mut() {
+                if let Some(reaction) =
+                    TypedWidget::<T, B>::event(content, origin, size, data, event.clone())
+                {
+                    return Some(reaction);
+                }
+            }
+        }
+
+        let click = match TransformEvent::<MouseClickEvent>::transform_event(event) {
+            Some(MouseClickEvent {
+                pos,
+                mouse_button: MouseButton::Left,
+            }) => pos - origin,
+            _ => return None,
+        };
+        if click.y < 0. || click.y >= self.bar_height {
+            return None;
+        }
+
+        let clicked = self
+            .positions
+            .iter()
+            .zip(self.tabs.iter())
+            .position(|(x, tab)| click.x >= *x && click.x < x + tab.label_size.width);
+        let index = clicked?;
+        self.selected.with_mut(data, |selected| *selected = index);
+        Some(CommonReaction::ReLayout.into())
+    }
+}
@@ -2,4 +2,15 @@ use utopia_core::math::Size;
 
 pub struct MeasureBrush<Font> {
     pub measure: Box<dyn Fn(&str, Font, u16) -> Size>,
+    /// Distance from the top of a line of text to its baseline
+    pub ascent: Box<dyn Fn(Font, u16) -> f32>,
+    /// Font size of the current text, which `em` units are relative to
+    pub font_size: u16,
+}
+
+impl<Font> MeasureBrush<Font> {
+    /// Length of one `em` for `font` at `font_size`, used to resolve font-relative units
+    pub fn em(&self, font: Font, font_size: u16) -> f32 {
+        (self.measure)("M", font, font_size).height
+    }
 }
@@ -24,6 +24,12 @@ impl<Font: Default, Color: Default> Label<Font, Color> {
     }
 }
 
+impl<Font: Clone, Color> Label<Font, Color> {
+    fn measure(&self, context: &MeasureBrush<Font>) -> Size {
+        (context.measure)(self.content, self.font.clone(), self.font_size)
+    }
+}
+
 impl<T, Font: Clone, Color: Clone> Widget<T> for Label<Font, Color> {
     type Primitive = TextPrimitive<Font, Color>;
     type Context = MeasureBrush<Font>;
@@ -31,10 +37,23 @@ impl<T, Font: Clone, Color: Clone> Widget<T> for Label<Font, Color> {
     type Reaction = ();
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, _data: &T) -> Size {
-        let size = (context.measure)(self.content, self.font.clone(), self.font_size.clone());
+        let size = self.measure(context);
         bc.constrain(size)
     }
 
+    // Labels don't wrap, so their intrinsic size doesn't depend on the other axis
+    fn max_intrinsic_width(&mut self, _height: f32, context: &Self::Context, _data: &T) -> f32 {
+        self.measure(context).width
+    }
+
+    fn max_intrinsic_height(&mut self, _width: f32, context: &Self::Context, _data: &T) -> f32 {
+        self.measure(context).height
+    }
+
+    fn baseline(&self, context: &Self::Context, _data: &T) -> Option<f32> {
+        Some((context.ascent)(self.font.clone(), self.font_size))
+    }
+
     fn draw(&self, origin: Vector2, size: Size, _data: &T) -> Self::Primitive {
         TextPrimitive {
             content: self.content.to_string(),
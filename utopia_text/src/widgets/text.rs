@@ -31,6 +31,12 @@ impl<Font: Default, Color: Default> Text<Font, Color> {
     }
 }
 
+impl<Font: Clone, Color> Text<Font, Color> {
+    fn measure(&self, context: &MeasureBrush<Font>, content: &str) -> Size {
+        (context.measure)(content, self.font.clone(), self.font_size)
+    }
+}
+
 impl<T: Display, Font: Clone, Color: Clone> Widget<T> for Text<Font, Color> {
     type Primitive = TextPrimitive<Font, Color>;
     type Context = MeasureBrush<Font>;
@@ -38,11 +44,23 @@ impl<T: Display, Font: Clone, Color: Clone> Widget<T> for Text<Font, Color> {
     type Reaction = ();
 
     fn layout(&mut self, bc: &BoxConstraints, context: &Self::Context, data: &T) -> Size {
-        let data = data.to_string();
-        let size = (context.measure)(data.as_str(), self.font.clone(), self.font_size.clone());
+        let size = self.measure(context, &data.to_string());
         bc.constrain(size)
     }
 
+    // Text doesn't wrap, so its intrinsic size doesn't depend on the other axis
+    fn max_intrinsic_width(&mut self, _height: f32, context: &Self::Context, data: &T) -> f32 {
+        self.measure(context, &data.to_string()).width
+    }
+
+    fn max_intrinsic_height(&mut self, _width: f32, context: &Self::Context, data: &T) -> f32 {
+        self.measure(context, &data.to_string()).height
+    }
+
+    fn baseline(&self, context: &Self::Context, _data: &T) -> Option<f32> {
+        Some((context.ascent)(self.font.clone(), self.font_size))
+    }
+
     fn draw(&self, origin: Vector2, size: Size, data: &T) -> Self::Primitive {
         TextPrimitive {
             content: data.to_string(),
@@ -14,11 +14,13 @@ fn main() {
         font: (),
         font_size: 16,
         color: (),
+        ..Text::default()
     };
     let text_other = Text {
         font: (),
         font_size: 20,
         color: (),
+        ..Text::default()
     };
 
     let size = backend
@@ -1,18 +1,34 @@
 use std::io::{Stdout, Write};
-use terminal::{Action, Clear, Terminal};
-use utopia_core::{contexts::ContextProvider, math::Size, Backend, CommonPrimitive};
+use terminal::{Action, Clear, Event, MouseEvent, Retrieved, Terminal, Value};
+use utopia_core::{
+    contexts::ContextProvider,
+    controllers::{
+        click::{MouseButton, MouseClickEvent},
+        TransformEvent,
+    },
+    math::{LayoutDirection, Size, Vector2},
+    reactions::{CommonReaction, LayoutReaction},
+    Backend, CommonPrimitive,
+};
 use utopia_decorations::primitives::quad::QuadPrimitive;
+use utopia_layout::LayoutUnits;
 use utopia_text::{context::MeasureBrush, primitives::text::TextPrimitive};
 pub struct TerminalBackend {
     measure_brush: MeasureBrush<()>,
+    units: LayoutUnits,
+    direction: LayoutDirection,
     pub terminal: Terminal<Stdout>,
 }
 
 pub type Align<T> = utopia_layout::widgets::align::Align<T, TerminalBackend>;
 pub type Color = ();
 pub type Flex<T> = utopia_layout::widgets::flex::Flex<T, TerminalBackend>;
+pub type Responsive<T> = utopia_layout::widgets::responsive::Responsive<T, TerminalBackend>;
 pub type Text = utopia_text::widgets::text::Text<(), Color>;
 pub type Border<T> = utopia_decorations::widgets::border::Border<T, Color, TerminalBackend>;
+pub type Table<R> = utopia_table::widgets::table::Table<R, Color, TerminalBackend>;
+pub type TableColumn<R> = utopia_table::widgets::table::TableColumn<R, TerminalBackend>;
+pub type Tabs<T, L> = utopia_tabs::widgets::tabs::Tabs<T, L, Color, TerminalBackend>;
 
 fn display_blank(
     terminal: &mut Terminal<Stdout>,
@@ -26,6 +42,26 @@ fn display_blank(
 }
 
 impl TerminalBackend {
+    /// Sets the size that viewport-relative units are resolved against
+    pub fn set_viewport(&mut self, size: Size) {
+        self.units.viewport = size;
+    }
+
+    /// Sets the font size of the current text, that `em` units are resolved against.
+    ///
+    /// Layouts cached before are kept until `Interface::request_layout` is called
+    pub fn set_font_size(&mut self, font_size: u16) {
+        self.measure_brush.font_size = font_size;
+        self.units.em = self.measure_brush.em((), font_size);
+    }
+
+    /// Sets the direction in which layouts flow horizontally.
+    ///
+    /// Call `Interface::request_layout` afterwards, as layouts cached before keep the previous direction
+    pub fn set_direction(&mut self, direction: LayoutDirection) {
+        self.direction = direction;
+    }
+
     pub fn draw_primitive(&mut self, primitive: TerminalPrimitive) -> terminal::error::Result<()> {
         match primitive {
             TerminalPrimitive::Common(common) => match common {
@@ -101,10 +137,21 @@ impl Default for TerminalBackend {
             .act(Action::EnableRawMode)
             .expect("Failed to enable raw mode");
 
+        let viewport = match terminal.get(Value::TerminalSize) {
+            Ok(Retrieved::TerminalSize(columns, rows)) => Size::new(columns as f32, rows as f32),
+            _ => Size::ZERO,
+        };
+        let measure_brush = MeasureBrush {
+            measure: Box::new(measure_text),
+            ascent: Box::new(text_ascent),
+            font_size: 16,
+        };
+        let rem = measure_brush.em((), 16);
+        let em = measure_brush.em((), measure_brush.font_size);
         TerminalBackend {
-            measure_brush: MeasureBrush {
-                measure: Box::new(measure_text),
-            },
+            measure_brush,
+            units: LayoutUnits { viewport, em, rem },
+            direction: LayoutDirection::default(),
             terminal,
         }
     }
@@ -124,12 +171,29 @@ fn measure_text(contents: &str, _font: (), _font_size: u16) -> Size {
     }
 }
 
+/// Text sits on the bottom of its row
+fn text_ascent(_font: (), _font_size: u16) -> f32 {
+    1.
+}
+
 impl ContextProvider<MeasureBrush<()>> for TerminalBackend {
     fn provide(&self) -> &MeasureBrush<()> {
         &self.measure_brush
     }
 }
 
+impl ContextProvider<LayoutDirection> for TerminalBackend {
+    fn provide(&self) -> &LayoutDirection {
+        &self.direction
+    }
+}
+
+impl ContextProvider<LayoutUnits> for TerminalBackend {
+    fn provide(&self) -> &LayoutUnits {
+        &self.units
+    }
+}
+
 impl ContextProvider<()> for TerminalBackend {
     fn provide(&self) -> &() {
         &()
@@ -166,8 +230,81 @@ impl From<TextPrimitive<(), Color>> for TerminalPrimitive {
     }
 }
 
+#[derive(Debug, Clone)]
+pub enum TerminalEvent {
+    MouseClick(MouseClickEvent),
+}
+
+impl TerminalEvent {
+    /// Converts an event read from the terminal, if widgets can handle it
+    pub fn from_terminal(event: Event) -> Option<Self> {
+        match event {
+            Event::Mouse(MouseEvent::Down(button, column, row, _)) => {
+                let mouse_button = match button {
+                    terminal::MouseButton::Left => MouseButton::Left,
+                    terminal::MouseButton::Right => MouseButton::Right,
+                    terminal::MouseButton::Middle => MouseButton::Middle,
+                    terminal::MouseButton::Unknown => MouseButton::Other(0),
+                };
+                Some(TerminalEvent::MouseClick(MouseClickEvent {
+                    pos: Vector2::new(column as f32, row as f32),
+                    mouse_button,
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TransformEvent<()> for TerminalEvent {
+    fn transform_event(self) -> Option<()> {
+        Some(())
+    }
+}
+
+impl TransformEvent<MouseClickEvent> for TerminalEvent {
+    fn transform_event(self) -> Option<MouseClickEvent> {
+        match self {
+            TerminalEvent::MouseClick(click) => Some(click),
+        }
+    }
+}
+
+pub enum TerminalReaction {
+    Common(CommonReaction),
+    None,
+}
+
+impl From<()> for TerminalReaction {
+    fn from(_input: ()) -> Self {
+        TerminalReaction::None
+    }
+}
+
+impl From<CommonReaction> for TerminalReaction {
+    fn from(input: CommonReaction) -> Self {
+        TerminalReaction::Common(input)
+    }
+}
+
+impl LayoutReaction for TerminalReaction {
+    fn requests_layout(&self) -> bool {
+        match self {
+            TerminalReaction::Common(common) => common.requests_layout(),
+            TerminalReaction::None => false,
+        }
+    }
+
+    fn requests_full_layout(&self) -> bool {
+        match self {
+            TerminalReaction::Common(common) => common.requests_full_layout(),
+            TerminalReaction::None => false,
+        }
+    }
+}
+
 impl Backend for TerminalBackend {
     type Primitive = TerminalPrimitive;
-    type Event = ();
-    type EventReaction = ();
+    type Event = TerminalEvent;
+    type EventReaction = TerminalReaction;
 }